@@ -1,9 +1,13 @@
-use leptonic::{Button, ButtonStyle};
+use leptonic::{
+    confirm, Button, ButtonSize, ButtonStyle, ButtonType, ButtonVariant, OnClickCallback,
+};
 use leptos::*;
 
 #[component]
 pub fn Demo(cx: Scope) -> impl IntoView {
     let (state, set_state) = create_signal(cx, ButtonStyle::Primary);
+    let (saves, set_saves) = create_signal(cx, 0);
+    let (loading, set_loading) = create_signal(cx, false);
 
     view! { cx,
         <Button
@@ -31,9 +35,58 @@ pub fn Demo(cx: Scope) -> impl IntoView {
             disabled=true
         />
         <span>"Last clicked: "{move || state.with(|s| s.style_name())}</span>
+        <Button
+            text="Save"
+            on_click=OnClickCallback::new_async(move || async move {
+                if confirm(cx, "Save changes", "Save your changes?").await {
+                    set_saves.update(|saves| *saves += 1);
+                }
+            })
+        />
+        <span>"Saved "{saves}" times"</span>
+        <Button
+            text="Loading"
+            style=ButtonStyle::Secondary
+            loading
+            on_click=move || set_loading(false)
+        />
+        <Button text="Toggle loading" on_click=move || set_loading.update(|l| *l = !*l) />
+        <div class="leptonic-demo-item-row">
+            <Button text="Small" size=ButtonSize::Small />
+            <Button text="Medium" />
+            <Button text="Large" size=ButtonSize::Large />
+        </div>
+        <div class="leptonic-demo-item-row">
+            <Button text="Outline" variant=ButtonVariant::Outline />
+            <Button text="Ghost" variant=ButtonVariant::Ghost />
+            <Button text="Danger outline" style=ButtonStyle::Danger variant=ButtonVariant::Outline />
+            <Button text="Disabled outline" variant=ButtonVariant::Outline disabled=true />
+        </div>
+        <div class="leptonic-demo-item-row">
+            <Button text="Pick a date" leading_icon=icon(cx, "calendar") />
+            <Button text="Next" style=ButtonStyle::Secondary trailing_icon=icon(cx, "angle-right") />
+            <Button leading_icon=icon(cx, "xmark") label="Close" variant=ButtonVariant::Ghost />
+            <Button leading_icon=icon(cx, "xmark") label="Close" size=ButtonSize::Small />
+        </div>
+        <form on:submit=|ev| ev.prevent_default()>
+            <Button button_type=ButtonType::Submit full_width=true>
+                <strong>"Submit"</strong>
+                " form"
+            </Button>
+        </form>
+        <Button text="Link to the Leptos docs" href="https://leptos.dev" variant=ButtonVariant::Outline />
     }
 }
 
+/// Renders one of the theme's icons.
+fn icon(cx: Scope, name: &str) -> View {
+    let style = format!(
+        "width: 100%; height: 100%; background: var(--leptonic-{name}-icon) center / contain no-repeat; filter: var(--leptonic-icon-filter);"
+    );
+
+    view! { cx, <span style=style></span> }.into_view(cx)
+}
+
 #[allow(dead_code)]
 fn main() {
     _ = console_log::init_with_level(log::Level::Debug);
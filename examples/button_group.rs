@@ -0,0 +1,63 @@
+use leptonic::{
+    Button, ButtonGroup, ButtonStyle, ButtonVariant, SegmentedControl, SelectOption, SplitButton,
+    SplitButtonAction,
+};
+use leptos::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum View {
+    List,
+    Grid,
+    Board,
+}
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (view_mode, set_view_mode) = create_signal(cx, View::List);
+    let (last_action, set_last_action) = create_signal(cx, String::new());
+
+    let views = vec![
+        SelectOption::new(View::List, "List"),
+        SelectOption::new(View::Grid, "Grid"),
+        SelectOption::new(View::Board, "Board").disabled(true),
+    ];
+    let actions = vec![
+        SplitButtonAction::new("Save as draft", move || {
+            set_last_action("Saved as draft".to_owned())
+        }),
+        SplitButtonAction::new("Save and close", move || {
+            set_last_action("Saved and closed".to_owned())
+        }),
+        SplitButtonAction::new("Publish", || {}).disabled(true),
+    ];
+
+    view! { cx,
+        <ButtonGroup label="Text alignment">
+            <Button text="Left" />
+            <Button text="Center" />
+            <Button text="Right" />
+        </ButtonGroup>
+        <ButtonGroup label="History">
+            <Button text="Undo" style=ButtonStyle::Secondary variant=ButtonVariant::Outline />
+            <Button text="Redo" style=ButtonStyle::Secondary variant=ButtonVariant::Outline />
+        </ButtonGroup>
+        <SegmentedControl state=view_mode set_state=set_view_mode options=views label="View" />
+        <p>{move || format!("View: {:?}", view_mode())}</p>
+        <SplitButton
+            text="Save"
+            on_click=move || set_last_action("Saved".to_owned())
+            actions=actions
+        />
+        <p>{move || format!("Last action: {}", last_action())}</p>
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
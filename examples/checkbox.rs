@@ -1,9 +1,18 @@
-use leptonic::Checkbox;
+use leptonic::{Checkbox, CheckboxGroup, CheckboxOption, CheckboxState, TriStateCheckbox};
 use leptos::*;
 
 #[component]
 pub fn Demo(cx: Scope) -> impl IntoView {
     let (state, set_state) = create_signal(cx, true);
+    let (tri_state, set_tri_state) = create_signal(cx, CheckboxState::Indeterminate);
+    let (toppings, set_toppings) = create_signal(cx, vec!["cheese"]);
+
+    let topping_options = vec![
+        CheckboxOption::new("cheese", "Cheese"),
+        CheckboxOption::new("mushrooms", "Mushrooms"),
+        CheckboxOption::new("olives", "Olives"),
+        CheckboxOption::new("anchovies", "Anchovies (sold out)").disabled(true),
+    ];
 
     view! { cx,
         <Checkbox
@@ -18,6 +27,19 @@ pub fn Demo(cx: Scope) -> impl IntoView {
             label="Disabled checkbox"
             disabled=true
         />
+        <TriStateCheckbox
+            state=tri_state
+            set_state=set_tri_state
+            label="Tri-state checkbox"
+        />
+        <span>"Value: "{move || tri_state().state_name()}</span>
+        <CheckboxGroup
+            state=toppings
+            set_state=set_toppings
+            options=topping_options
+            label="All toppings"
+        />
+        <span>"Toppings: "{move || toppings().join(", ")}</span>
     }
 }
 
@@ -0,0 +1,44 @@
+use leptonic::{Accordion, AccordionItem, AccordionMode, Collapsible};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (open, set_open) = create_signal(cx, false);
+    let (single, set_single) = create_signal(cx, vec!["first".to_owned()]);
+    let (multiple, set_multiple) = create_signal(cx, Vec::<String>::new());
+
+    view! { cx,
+        <Collapsible state=open set_state=set_open title="Details">
+            <p>"Hidden details that can be expanded."</p>
+        </Collapsible>
+        <Accordion state=single set_state=set_single>
+            <AccordionItem key="first" title="First section">
+                <p>"Only one section is open at a time."</p>
+            </AccordionItem>
+            <AccordionItem key="second" title="Second section">
+                <p>"Opening this section closes the first."</p>
+            </AccordionItem>
+            <AccordionItem key="third" title="Disabled section" disabled=true>
+                <p>"This section cannot be opened."</p>
+            </AccordionItem>
+        </Accordion>
+        <Accordion state=multiple set_state=set_multiple mode=AccordionMode::Multiple>
+            <AccordionItem key="a" title="Section A">
+                <p>"Any number of sections may be open."</p>
+            </AccordionItem>
+            <AccordionItem key="b" title="Section B">
+                <p>"Open sections: " {move || multiple().join(", ")}</p>
+            </AccordionItem>
+        </Accordion>
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -0,0 +1,54 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use leptonic::DatePicker;
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (date, set_date) = create_signal(cx, NaiveDate::from_ymd_opt(2023, 7, 14));
+    let (weekday, set_weekday) = create_signal(cx, None::<NaiveDate>);
+    let date_error = Signal::derive(cx, move || {
+        date()
+            .is_none()
+            .then_some("Please choose a date".to_owned())
+    });
+
+    view! { cx,
+        <DatePicker
+            state=date
+            set_state=set_date
+            label="Date picker label"
+            placeholder="YYYY-MM-DD"
+            required=true
+            error=date_error
+        />
+        <span>"Value: "{move || date().map(|date| date.to_string()).unwrap_or_default()}</span>
+        <DatePicker
+            state=weekday
+            set_state=set_weekday
+            label="Weekdays in 2023, weeks starting on Monday"
+            placeholder="DD/MM/YYYY"
+            format="%d/%m/%Y"
+            min=NaiveDate::from_ymd_opt(2023, 1, 1)
+            max=NaiveDate::from_ymd_opt(2023, 12, 31)
+            disabled_dates=|date: NaiveDate| matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+            first_day_of_week=Weekday::Mon
+        />
+        <span>"Value: "{move || weekday().map(|date| date.to_string()).unwrap_or_default()}</span>
+        <DatePicker
+            state=date
+            set_state=set_date
+            label="Disabled date picker"
+            disabled=true
+        />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -0,0 +1,52 @@
+use chrono::NaiveDate;
+use leptonic::{DateRange, DateRangePicker, DateRangePreset};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (range, set_range) = create_signal(cx, None::<DateRange>);
+    let (bounded, set_bounded) = create_signal(cx, None::<DateRange>);
+    let range_error = Signal::derive(cx, move || {
+        range()
+            .is_none()
+            .then_some("Please choose a date range".to_owned())
+    });
+    let describe = |range: Option<DateRange>| {
+        range
+            .map(|range| format!("{} to {} ({} days)", range.start, range.end, range.days()))
+            .unwrap_or_default()
+    };
+
+    view! { cx,
+        <DateRangePicker
+            state=range
+            set_state=set_range
+            label="Date range picker label"
+            placeholder="Choose a range"
+            presets=DateRangePreset::defaults()
+            required=true
+            error=range_error
+        />
+        <span>"Value: "{move || describe(range())}</span>
+        <DateRangePicker
+            state=bounded
+            set_state=set_bounded
+            label="Summer 2023"
+            placeholder="Choose a range"
+            format="%d/%m/%Y"
+            min=NaiveDate::from_ymd_opt(2023, 6, 1)
+            max=NaiveDate::from_ymd_opt(2023, 8, 31)
+        />
+        <span>"Value: "{move || describe(bounded())}</span>
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
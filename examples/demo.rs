@@ -2,13 +2,32 @@ use convert_case::{Case, Casing};
 use leptos::*;
 
 mod button;
+mod button_group;
 mod checkbox;
+mod collapsible;
+mod date_picker;
+mod date_range_picker;
+mod dialog;
 mod error;
 mod input;
+mod modal;
+mod multi_select;
 mod number_input;
+mod pagination;
+mod popover;
+mod progress;
+mod radio_group;
+mod select;
+mod slider;
 mod switch;
+mod table;
+mod tabs;
 mod textarea;
 mod theme;
+mod time_picker;
+mod toast;
+mod tooltip;
+mod virtual_list;
 
 macro_rules! demo_views {
     ( $cx:expr, [$( $demo:ident ),*] ) => {{
@@ -42,9 +61,28 @@ fn main() {
                 input,
                 textarea,
                 number_input,
+                select,
+                multi_select,
+                date_picker,
+                date_range_picker,
+                time_picker,
                 button,
                 checkbox,
-                switch
+                switch,
+                modal,
+                dialog,
+                toast,
+                tooltip,
+                popover,
+                tabs,
+                collapsible,
+                radio_group,
+                slider,
+                progress,
+                button_group,
+                table,
+                virtual_list,
+                pagination
             ]
         );
 
@@ -0,0 +1,61 @@
+use leptonic::{confirm, Button, ButtonStyle, ConfirmDialog, PromptDialog};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (result, set_result) = create_signal(cx, String::new());
+
+    let on_confirm = move || {
+        spawn_local(async move {
+            let confirmed = confirm(cx, "Continue?", "Do you want to continue?").await;
+            set_result(format!("Confirmed: {confirmed}"));
+        });
+    };
+    let on_delete = move || {
+        spawn_local(async move {
+            let confirmed = ConfirmDialog::new(
+                "Delete item",
+                "This item will be permanently deleted. This cannot be undone.",
+            )
+            .confirm_text("Delete")
+            .danger()
+            .show(cx)
+            .await;
+            set_result(if confirmed {
+                "Item deleted".to_owned()
+            } else {
+                "Deletion cancelled".to_owned()
+            });
+        });
+    };
+    let on_prompt = move || {
+        spawn_local(async move {
+            let name = PromptDialog::new("Rename", "New name")
+                .default_value("Untitled")
+                .placeholder("Enter a name")
+                .show(cx)
+                .await;
+            set_result(match name {
+                Some(name) => format!("Renamed to {name:?}"),
+                None => "Rename cancelled".to_owned(),
+            });
+        });
+    };
+
+    view! { cx,
+        <Button text="Confirm" on_click=on_confirm />
+        <Button text="Delete" style=ButtonStyle::Danger on_click=on_delete />
+        <Button text="Prompt" style=ButtonStyle::Secondary on_click=on_prompt />
+        <span>{result}</span>
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -0,0 +1,64 @@
+use leptonic::{Button, ButtonStyle, Checkbox, Modal, ModalAction, ModalSize};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (open, set_open) = create_signal(cx, false);
+    let (nested_open, set_nested_open) = create_signal(cx, false);
+    let (locked, set_locked) = create_signal(cx, false);
+    let (saved, set_saved) = create_signal(cx, 0usize);
+
+    view! { cx,
+        <Button
+            text="Open modal"
+            on_click=move || set_open(true)
+        />
+        <span>"Times saved: "{saved}</span>
+        <Modal
+            state=open
+            set_state=set_open
+            title="Modal title"
+            on_dismiss=move |_| !locked()
+            actions=vec![
+                ModalAction::new("Open another")
+                    .style(ButtonStyle::Secondary)
+                    .on_click(move || set_nested_open(true)),
+                ModalAction::new("Cancel")
+                    .style(ButtonStyle::Secondary)
+                    .on_click(move || set_open(false)),
+                ModalAction::new("Save").on_click(move || {
+                    set_saved.update(|n| *n += 1);
+                    set_open(false);
+                }),
+            ]
+        >
+            <p>"Press Escape or click the backdrop to dismiss this modal, unless it is locked."</p>
+            <Checkbox
+                state=locked
+                set_state=set_locked
+                label="Prevent dismissal"
+            />
+        </Modal>
+        <Modal
+            state=nested_open
+            set_state=set_nested_open
+            title="Nested modal"
+            size=ModalSize::Small
+            actions=vec![
+                ModalAction::new("Close").on_click(move || set_nested_open(false)),
+            ]
+        >
+            <p>"This modal is stacked above the first one."</p>
+        </Modal>
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
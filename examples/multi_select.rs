@@ -0,0 +1,72 @@
+use leptonic::{MultiSelect, SelectOption};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (colors, set_colors) = create_signal(cx, vec!["red".to_owned()]);
+    let (toppings, set_toppings) = create_signal(cx, Vec::<u8>::new());
+    let colors_error = Signal::derive(cx, move || {
+        colors.with(|values| {
+            values
+                .is_empty()
+                .then_some("Please choose at least one color".to_owned())
+        })
+    });
+    let color_options = vec![
+        SelectOption::new("red".to_owned(), "Red").group("Warm"),
+        SelectOption::new("orange".to_owned(), "Orange").group("Warm"),
+        SelectOption::new("yellow".to_owned(), "Yellow").group("Warm"),
+        SelectOption::new("green".to_owned(), "Green").group("Cool"),
+        SelectOption::new("blue".to_owned(), "Blue").group("Cool"),
+        SelectOption::new("purple".to_owned(), "Purple")
+            .group("Cool")
+            .disabled(true),
+    ];
+    let topping_options = vec![
+        SelectOption::new(1u8, "Cheese"),
+        SelectOption::new(2u8, "Mushrooms"),
+        SelectOption::new(3u8, "Olives"),
+        SelectOption::new(4u8, "Onions"),
+        SelectOption::new(5u8, "Peppers"),
+    ];
+
+    view! { cx,
+        <MultiSelect
+            state=colors
+            set_state=set_colors
+            options=color_options.clone()
+            label="Multi-select label"
+            placeholder="Search colors"
+            select_all=true
+            required=true
+            error=colors_error
+        />
+        <span>"Value: "{move || colors().join(", ")}</span>
+        <MultiSelect
+            state=toppings
+            set_state=set_toppings
+            options=topping_options
+            label="Up to three toppings"
+            placeholder="Search toppings"
+            max=Some(3)
+        />
+        <span>"Value: "{move || format!("{:?}", toppings())}</span>
+        <MultiSelect
+            state=colors
+            set_state=set_colors
+            options=color_options
+            label="Disabled multi-select"
+            disabled=true
+        />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
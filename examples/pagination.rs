@@ -0,0 +1,26 @@
+use leptonic::Pagination;
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (page, set_page) = create_signal(cx, 0);
+    let (page_size, set_page_size) = create_signal(cx, 10);
+    let (small_page, set_small_page) = create_signal(cx, 0);
+
+    view! { cx,
+        <Pagination page set_page total=1234 page_size set_page_size=set_page_size jump=true />
+        <p>{move || format!("Page {} with {} items per page", page() + 1, page_size())}</p>
+        <Pagination page=small_page set_page=set_small_page total=45 siblings=2 />
+        <Pagination page set_page total=1234 page_size compact=true />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
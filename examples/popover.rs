@@ -0,0 +1,31 @@
+use leptonic::{Checkbox, FloatingTrigger, Placement, Popover};
+use leptos::html::Button;
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let settings = create_node_ref::<Button>(cx);
+    let details = create_node_ref::<Button>(cx);
+    let (notify, set_notify) = create_signal(cx, true);
+
+    view! { cx,
+        <button class="leptonic-button leptonic-button-primary" node_ref=settings>"Settings"</button>
+        <Popover anchor=settings title="Settings">
+            <Checkbox state=notify set_state=set_notify label="Send notifications" />
+        </Popover>
+        <button class="leptonic-button leptonic-button-secondary" node_ref=details>"Details"</button>
+        <Popover anchor=details placement=Placement::RIGHT_START trigger=FloatingTrigger::Hover>
+            <p>"Popovers can also open on hover, and stay open while the pointer is over them."</p>
+        </Popover>
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
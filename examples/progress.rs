@@ -0,0 +1,38 @@
+use leptonic::{Button, LoadingOverlay, ProgressBar, ProgressColor, Spinner, SpinnerSize};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (progress, set_progress) = create_signal(cx, 30.0);
+    let (loading, set_loading) = create_signal(cx, false);
+
+    view! { cx,
+        <ProgressBar value=Signal::derive(cx, move || Some(progress())) label="Upload" />
+        <div class="leptonic-demo-item-row">
+            <Button text="-10%" on_click=move || set_progress.update(|p| *p = (*p - 10.0).max(0.0)) />
+            <Button text="+10%" on_click=move || set_progress.update(|p| *p = (*p + 10.0).min(100.0)) />
+        </div>
+        <ProgressBar value=Some(100.0) color=ProgressColor::Success label="Complete" />
+        <ProgressBar label="Indeterminate" />
+        <div class="leptonic-demo-item-row">
+            <Spinner size=SpinnerSize::Small />
+            <Spinner color=ProgressColor::Warning />
+            <Spinner size=SpinnerSize::Large color=ProgressColor::Danger />
+        </div>
+        <LoadingOverlay loading label="Saving...">
+            <p>"Content that is covered while loading."</p>
+            <Button text="Load" on_click=move || set_loading(true) />
+        </LoadingOverlay>
+        <Button text="Stop loading" on_click=move || set_loading(false) />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
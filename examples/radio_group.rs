@@ -0,0 +1,52 @@
+use leptonic::{RadioGroup, RadioGroupLayout, RadioOption};
+use leptos::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Plan {
+    Free,
+    Pro,
+    Enterprise,
+}
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (plan, set_plan) = create_signal(cx, Plan::Free);
+    let (size, set_size) = create_signal(cx, String::new());
+
+    let plans = vec![
+        RadioOption::new(Plan::Free, "Free").description("For personal projects"),
+        RadioOption::new(Plan::Pro, "Pro").description("For small teams"),
+        RadioOption::new(Plan::Enterprise, "Enterprise")
+            .description("Contact sales")
+            .disabled(true),
+    ];
+    let sizes = ["Small", "Medium", "Large"]
+        .into_iter()
+        .map(|size| RadioOption::new(size.to_owned(), size))
+        .collect::<Vec<_>>();
+    let size_error = move || size.with(|size| size.is_empty().then(|| "Pick a size".to_owned()));
+
+    view! { cx,
+        <RadioGroup state=plan set_state=set_plan options=plans label="Plan" />
+        <RadioGroup
+            state=size
+            set_state=set_size
+            options=sizes
+            label="Size"
+            layout=RadioGroupLayout::Horizontal
+            required=true
+            error=Signal::derive(cx, size_error)
+        />
+        <p>{move || format!("Plan: {:?}, size: {}", plan(), size())}</p>
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -0,0 +1,68 @@
+use leptonic::{Select, SelectOption};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (fruit, set_fruit) = create_signal(cx, String::new());
+    let (number, set_number) = create_signal(cx, 2u8);
+    let fruit_error = Signal::derive(cx, move || {
+        fruit.with(|value| {
+            value
+                .is_empty()
+                .then_some("Please choose a fruit".to_owned())
+        })
+    });
+    let fruits = vec![
+        SelectOption::new("apple".to_owned(), "Apple"),
+        SelectOption::new("banana".to_owned(), "Banana"),
+        SelectOption::new("blueberry".to_owned(), "Blueberry"),
+        SelectOption::new("cherry".to_owned(), "Cherry").disabled(true),
+        SelectOption::new("grape".to_owned(), "Grape"),
+        SelectOption::new("orange".to_owned(), "Orange"),
+    ];
+    let numbers = vec![
+        SelectOption::new(1u8, "One").group("Odd"),
+        SelectOption::new(3u8, "Three").group("Odd"),
+        SelectOption::new(5u8, "Five").group("Odd"),
+        SelectOption::new(2u8, "Two").group("Even"),
+        SelectOption::new(4u8, "Four").group("Even"),
+    ];
+
+    view! { cx,
+        <Select
+            state=fruit
+            set_state=set_fruit
+            options=fruits.clone()
+            label="Select label"
+            placeholder="Choose a fruit"
+            required=true
+            error=fruit_error
+        />
+        <span>"Value: "{fruit}</span>
+        <Select
+            state=number
+            set_state=set_number
+            options=numbers
+            label="Grouped select"
+        />
+        <span>"Value: "{number}</span>
+        <Select
+            state=fruit
+            set_state=set_fruit
+            options=fruits
+            label="Disabled select"
+            placeholder="Choose a fruit"
+            disabled=true
+        />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
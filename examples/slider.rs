@@ -0,0 +1,51 @@
+use leptonic::{RangeSlider, Slider, SliderMark, SliderOrientation};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (volume, set_volume) = create_signal(cx, 40u8);
+    let (opacity, set_opacity) = create_signal(cx, 0.5f64);
+    let (price, set_price) = create_signal(cx, (200i32, 800i32));
+    let (level, set_level) = create_signal(cx, 3i32);
+
+    let price_marks = [0, 250, 500, 750, 1000]
+        .into_iter()
+        .map(|value| SliderMark::new(value).label(format!("${value}")))
+        .collect::<Vec<_>>();
+    let level_marks = (0..=5).map(SliderMark::new).collect::<Vec<_>>();
+
+    view! { cx,
+        <Slider state=volume set_state=set_volume min=0 max=100 label="Volume" number_input=true />
+        <Slider state=opacity set_state=set_opacity min=0.0 max=1.0 step=0.05 label="Opacity" />
+        <RangeSlider
+            state=price
+            set_state=set_price
+            min=0
+            max=1000
+            step=10
+            label="Price range"
+            marks=price_marks
+            number_input=true
+        />
+        <Slider
+            state=level
+            set_state=set_level
+            min=0
+            max=5
+            label="Level"
+            orientation=SliderOrientation::Vertical
+            marks=level_marks
+        />
+        <Slider state=volume set_state=set_volume min=0 max=100 label="Disabled" disabled=true />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -0,0 +1,94 @@
+use leptonic::{Button, Table, TableColumn};
+use leptos::*;
+
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    name: String,
+    email: String,
+    role: &'static str,
+    logins: u32,
+}
+
+fn users() -> Vec<User> {
+    [
+        ("Ada Lovelace", "ada@example.com", "Admin", 42),
+        ("Grace Hopper", "grace@example.com", "Editor", 17),
+        ("Alan Turing", "alan@example.com", "Viewer", 8),
+        ("Linus Torvalds", "linus@example.com", "Editor", 17),
+        ("Margaret Hamilton", "margaret@example.com", "Admin", 31),
+        ("Dennis Ritchie", "dennis@example.com", "Viewer", 3),
+        ("Barbara Liskov", "barbara@example.com", "Editor", 25),
+    ]
+    .into_iter()
+    .map(|(name, email, role, logins)| User {
+        name: name.to_owned(),
+        email: email.to_owned(),
+        role,
+        logins,
+    })
+    .collect()
+}
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (selected, set_selected) = create_signal(cx, Vec::<User>::new());
+    let (loading, set_loading) = create_signal(cx, false);
+
+    let columns = vec![
+        TableColumn::new("name", "Name", |_, user: &User| user.name.clone())
+            .sort_key(|user| user.name.clone())
+            .filter_text(|user| user.name.clone()),
+        TableColumn::new("email", "Email", |_, user: &User| user.email.clone())
+            .filter_text(|user| user.email.clone()),
+        TableColumn::new("role", "Role", |_, user: &User| user.role)
+            .sort_key(|user| user.role)
+            .filter_text(|user| user.role.to_owned())
+            .width("120px"),
+        TableColumn::new("logins", "Logins", |_, user: &User| user.logins)
+            .sort_key(|user| user.logins)
+            .width("100px"),
+    ];
+
+    let no_users = Vec::<User>::new();
+    let empty_columns = vec![TableColumn::new("name", "Name", |_, user: &User| {
+        user.name.clone()
+    })];
+
+    view! { cx,
+        <Table
+            rows=users()
+            columns=columns
+            row_key=|user: &User| user.email.clone()
+            label="Users"
+            page_size=Some(5)
+            selected
+            set_selected
+            loading
+        />
+        <p>
+            {move || {
+                let names = selected.with(|users| {
+                    users.iter().map(|user| user.name.as_str()).collect::<Vec<_>>().join(", ")
+                });
+                format!("Selected: {names}")
+            }}
+        </p>
+        <Button text="Toggle loading" on_click=move || set_loading.update(|loading| *loading = !*loading) />
+        <Table
+            rows=no_users
+            columns=empty_columns
+            row_key=|user: &User| user.email.clone()
+            empty_text="No users yet"
+        />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -0,0 +1,45 @@
+use leptonic::{Input, Tab, TabPanel, TabRendering, Tabs, TabsOverflow};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (selected, set_selected) = create_signal(cx, "profile".to_owned());
+    let (many_selected, set_many_selected) = create_signal(cx, String::new());
+    let (name, set_name) = create_signal(cx, String::new());
+
+    let many_tabs = (1..=12)
+        .map(|n| {
+            let key = format!("tab-{n}");
+            view! { cx,
+                <Tab key=key.clone() label=format!("Tab number {n}") disabled={ n == 3 } />
+                <TabPanel key=key>{format!("Content of tab {n}")}</TabPanel>
+            }
+        })
+        .collect_view(cx);
+
+    view! { cx,
+        <Tabs state=selected set_state=set_selected label="Account">
+            <Tab key="profile" label="Profile" />
+            <Tab key="security" label="Security" />
+            <Tab key="billing" label="Billing" disabled=true />
+            <TabPanel key="profile" rendering=TabRendering::Eager>
+                <Input state=name set_state=set_name label="Name (kept while switching tabs)" />
+            </TabPanel>
+            <TabPanel key="security">"Security settings"</TabPanel>
+            <TabPanel key="billing">"Billing settings"</TabPanel>
+        </Tabs>
+        <Tabs state=many_selected set_state=set_many_selected overflow=TabsOverflow::Menu>
+            {many_tabs}
+        </Tabs>
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -1,12 +1,48 @@
-use leptonic::use_theme;
+use leptonic::{on_theme_applied, use_theme, ColorMode, Switch, TextDirection};
 use leptos::*;
 
 #[component]
 pub fn Demo(cx: Scope) -> impl IntoView {
-    let (_theme, _set_theme) = use_theme(cx);
+    let (theme, set_theme) = use_theme(cx);
+    let (light_mode, set_light_mode) = create_signal(cx, false);
+    let (rtl, set_rtl) = create_signal(cx, false);
+    let (applied, set_applied) = create_signal(cx, 0usize);
+
+    set_theme.update(|t| t.set_transitions(true));
+
+    create_effect(cx, move |_| {
+        let color_mode = if light_mode() {
+            ColorMode::Light
+        } else {
+            ColorMode::Dark
+        };
+        set_theme.update(|t| t.set_color_mode(color_mode));
+    });
+
+    create_effect(cx, move |_| {
+        let direction = if rtl() {
+            TextDirection::Rtl
+        } else {
+            TextDirection::Ltr
+        };
+        set_theme.update(|t| t.set_direction(direction));
+    });
+
+    on_theme_applied(cx, move |_| set_applied.update(|n| *n += 1));
 
     view! { cx,
-        <div>"Theme customization placeholder"</div>
+        <Switch
+            state=light_mode
+            set_state=set_light_mode
+            label="Light mode"
+        />
+        <Switch
+            state=rtl
+            set_state=set_rtl
+            label="Right-to-left"
+        />
+        <span>"Color mode: "{move || format!("{:?}", theme.with(|t| t.color_mode))}</span>
+        <span>"Themes applied: "{applied}</span>
     }
 }
 
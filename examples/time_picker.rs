@@ -0,0 +1,70 @@
+use chrono::NaiveTime;
+use leptonic::{HourFormat, Switch, TimePicker};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (time, set_time) = create_signal(cx, NaiveTime::from_hms_opt(9, 30, 0));
+    let (meeting, set_meeting) = create_signal(cx, None::<NaiveTime>);
+    let (twelve_hour, set_twelve_hour) = create_signal(cx, false);
+    let (seconds, set_seconds) = create_signal(cx, false);
+    let hour_format = Signal::derive(cx, move || {
+        if twelve_hour() {
+            HourFormat::H12
+        } else {
+            HourFormat::H24
+        }
+    });
+    let meeting_error = Signal::derive(cx, move || {
+        meeting()
+            .is_none()
+            .then_some("Please choose a time".to_owned())
+    });
+
+    view! { cx,
+        <Switch
+            state=twelve_hour
+            set_state=set_twelve_hour
+            label="12-hour clock"
+        />
+        <Switch
+            state=seconds
+            set_state=set_seconds
+            label="Show seconds"
+        />
+        <TimePicker
+            state=time
+            set_state=set_time
+            label="Time picker label"
+            hour_format
+            seconds
+        />
+        <span>"Value: "{move || time().map(|time| time.to_string()).unwrap_or_default()}</span>
+        <TimePicker
+            state=meeting
+            set_state=set_meeting
+            label="Meeting time, in 15 minute steps"
+            hour_format
+            minute_step=15u32
+            required=true
+            error=meeting_error
+        />
+        <span>"Value: "{move || meeting().map(|time| time.to_string()).unwrap_or_default()}</span>
+        <TimePicker
+            state=time
+            set_state=set_time
+            label="Disabled time picker"
+            disabled=true
+        />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -0,0 +1,76 @@
+use leptonic::{
+    use_toasts, Button, ButtonStyle, Select, SelectOption, Toast, ToastContainer, ToastPosition,
+};
+use leptos::*;
+
+#[component]
+fn ToastButtons(cx: Scope) -> impl IntoView {
+    let toasts = use_toasts(cx);
+    let (undone, set_undone) = create_signal(cx, 0usize);
+
+    view! { cx,
+        <Button text="Success" on_click=move || { toasts.success("Changes saved"); } />
+        <Button
+            text="Info"
+            style=ButtonStyle::Secondary
+            on_click=move || { toasts.push(Toast::info("A new version is available").title("Update")); }
+        />
+        <Button
+            text="Warning"
+            style=ButtonStyle::Secondary
+            on_click=move || { toasts.warning("Your session expires soon"); }
+        />
+        <Button
+            text="Error"
+            style=ButtonStyle::Danger
+            on_click=move || {
+                toasts.push(Toast::error("Failed to connect to the server").persistent());
+            }
+        />
+        <Button
+            text="Deleted"
+            style=ButtonStyle::Secondary
+            on_click=move || {
+                toasts.push(
+                    Toast::info("Item deleted")
+                        .action("Undo", move || set_undone.update(|n| *n += 1)),
+                );
+            }
+        />
+        <Button text="Clear" style=ButtonStyle::Secondary on_click=move || toasts.clear() />
+        <span>"Times undone: "{undone}</span>
+    }
+}
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (position, set_position) = create_signal(cx, ToastPosition::BottomRight);
+    let options = vec![
+        SelectOption::new(ToastPosition::TopLeft, "Top left"),
+        SelectOption::new(ToastPosition::TopRight, "Top right"),
+        SelectOption::new(ToastPosition::BottomLeft, "Bottom left"),
+        SelectOption::new(ToastPosition::BottomRight, "Bottom right"),
+    ];
+
+    view! { cx,
+        <ToastContainer position=position>
+            <Select
+                state=position
+                set_state=set_position
+                options=options
+                label="Position"
+            />
+            <ToastButtons />
+        </ToastContainer>
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -0,0 +1,39 @@
+use leptonic::{FloatingTrigger, Placement, Tooltip};
+use leptos::html::{Button, Span};
+use leptos::*;
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let top = create_node_ref::<Button>(cx);
+    let right = create_node_ref::<Button>(cx);
+    let bottom_start = create_node_ref::<Button>(cx);
+    let focus = create_node_ref::<Span>(cx);
+
+    view! { cx,
+        <button class="leptonic-button leptonic-button-secondary" node_ref=top>"Top"</button>
+        <Tooltip anchor=top text="Shown above the button" />
+        <button class="leptonic-button leptonic-button-secondary" node_ref=right>"Right"</button>
+        <Tooltip anchor=right text="Shown to the right" placement=Placement::RIGHT />
+        <button class="leptonic-button leptonic-button-secondary" node_ref=bottom_start>
+            "Bottom start"
+        </button>
+        <Tooltip
+            anchor=bottom_start
+            text="Aligned with the start of the button, without an arrow"
+            placement=Placement::BOTTOM_START
+            arrow=false
+        />
+        <span tabindex="0" node_ref=focus>"Focus me"</span>
+        <Tooltip anchor=focus text="Shown on focus only" trigger=FloatingTrigger::Focus />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
@@ -0,0 +1,82 @@
+use leptonic::{Button, ItemHeight, ScrollAlign, Table, TableColumn, VirtualList};
+use leptos::*;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    id: usize,
+    text: String,
+}
+
+fn entries(count: usize) -> Vec<Entry> {
+    (0..count)
+        .map(|id| Entry {
+            id,
+            text: format!("Entry {}", id + 1),
+        })
+        .collect()
+}
+
+#[component]
+pub fn Demo(cx: Scope) -> impl IntoView {
+    let (scroll_to, set_scroll_to) = create_signal(cx, None::<usize>);
+
+    let columns = vec![
+        TableColumn::new("id", "ID", |_, entry: &Entry| entry.id)
+            .sort_key(|entry| entry.id)
+            .width("100px"),
+        TableColumn::new("text", "Text", |_, entry: &Entry| entry.text.clone())
+            .filter_text(|entry| entry.text.clone()),
+    ];
+    let variable_height =
+        ItemHeight::variable(|entry: &Entry| if entry.id % 3 == 0 { 56.0 } else { 32.0 });
+
+    view! { cx,
+        <Button text="Scroll to entry 5000" on_click=move || set_scroll_to(Some(4999)) />
+        <VirtualList
+            items=entries(10_000)
+            item_height=32.0
+            key=|entry: &Entry| entry.id
+            view=|cx, entry: Entry| view! { cx,
+                <div style="height: 32px; display: flex; align-items: center; padding: 0 8px;">
+                    {entry.text}
+                </div>
+            }
+            height="240px"
+            scroll_to=scroll_to
+            scroll_align=ScrollAlign::Center
+            label="Entries"
+        />
+        <VirtualList
+            items=entries(10_000)
+            item_height=variable_height
+            key=|entry: &Entry| entry.id
+            view=|cx, entry: Entry| {
+                let height = if entry.id % 3 == 0 { 56 } else { 32 };
+                view! { cx,
+                    <div style=format!("height: {height}px; display: flex; align-items: center; padding: 0 8px;")>
+                        {entry.text}
+                    </div>
+                }
+            }
+            height="240px"
+            label="Entries with variable heights"
+        />
+        <Table
+            rows=entries(10_000)
+            columns=columns
+            row_key=|entry: &Entry| entry.id
+            label="Entries"
+            row_height=Some(40.0)
+        />
+    }
+}
+
+#[allow(dead_code)]
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|cx| {
+        view! { cx, <Demo /> }
+    })
+}
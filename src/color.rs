@@ -0,0 +1,91 @@
+/// An RGBA color with 8 bits per channel.
+pub type Rgba8 = [u8; 4];
+
+/// Gets the value of a single hexadecimal digit.
+const fn hex_digit(byte: u8) -> u8 {
+    match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'f' => byte - b'a' + 10,
+        b'A'..=b'F' => byte - b'A' + 10,
+        _ => panic!("invalid hex color: expected only hexadecimal digits after `#`"),
+    }
+}
+
+/// Parses a hex color, such as `#6928ff`, `#6928ff80`, `#62f`, or `#62f8`.
+/// When evaluated in a const context, an invalid color is a compile error.
+///
+/// ```
+/// # use leptonic::parse_hex_color;
+/// const PURPLE: [u8; 4] = parse_hex_color("#6928ff");
+///
+/// assert_eq!(PURPLE, [105, 40, 255, 255]);
+/// ```
+pub const fn parse_hex_color(hex: &str) -> Rgba8 {
+    let bytes = hex.as_bytes();
+
+    if bytes.is_empty() || bytes[0] != b'#' {
+        panic!("invalid hex color: expected a leading `#`");
+    }
+
+    match bytes.len() {
+        4 | 5 => {
+            let mut rgba = [255; 4];
+            let mut index = 0;
+            while index < bytes.len() - 1 {
+                let digit = hex_digit(bytes[index + 1]);
+                rgba[index] = digit * 16 + digit;
+                index += 1;
+            }
+            rgba
+        }
+        7 | 9 => {
+            let mut rgba = [255; 4];
+            let mut index = 0;
+            while index < (bytes.len() - 1) / 2 {
+                rgba[index] =
+                    hex_digit(bytes[index * 2 + 1]) * 16 + hex_digit(bytes[index * 2 + 2]);
+                index += 1;
+            }
+            rgba
+        }
+        _ => panic!("invalid hex color: expected 3, 4, 6, or 8 hexadecimal digits"),
+    }
+}
+
+/// Mixes two colors together. The `amount` is the percentage of `color1`
+/// that should be mixed into `color2`.
+pub(crate) const fn mix(color1: Rgba8, color2: Rgba8, amount: u8) -> Rgba8 {
+    let amount = amount as u32;
+    let mut mixed = [0; 4];
+    let mut index = 0;
+    while index < 4 {
+        let channel = color1[index] as u32 * amount + color2[index] as u32 * (100 - amount);
+        mixed[index] = ((channel + 50) / 100) as u8;
+        index += 1;
+    }
+    mixed
+}
+
+/// Darkens a color by the specified percentage.
+pub(crate) const fn darken(color: Rgba8, amount: u8) -> Rgba8 {
+    mix(color, [0, 0, 0, color[3]], 100 - amount)
+}
+
+/// Determines whether text on the given background color should be light.
+pub(crate) const fn is_dark(background_color: Rgba8) -> bool {
+    // Equivalent to an average channel value below 60%.
+    (background_color[0] as u32 + background_color[1] as u32 + background_color[2] as u32) * 10
+        < 255 * 3 * 6
+}
+
+/// Formats a color as a hex string, omitting the alpha channel if the color
+/// is opaque.
+pub(crate) fn hex_string(color: Rgba8) -> String {
+    let [r, g, b, a] = color;
+
+    if a < 255 {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
@@ -1,5 +1,8 @@
+use super::progress::*;
 use crate::classes::*;
 use leptos::*;
+use std::future::Future;
+use std::pin::Pin;
 
 /// The style of a button.
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
@@ -27,15 +30,114 @@ impl ButtonStyle {
     }
 }
 
+/// The size of a button.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ButtonSize {
+    /// A small button.
+    Small,
+    /// A medium sized button.
+    #[default]
+    Medium,
+    /// A large button.
+    Large,
+}
+
+impl ButtonSize {
+    /// Gets the name of the button size.
+    pub fn size_name(&self) -> &'static str {
+        match *self {
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+        }
+    }
+}
+
+/// How a button is filled in with the color of its style.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ButtonVariant {
+    /// A solid background.
+    #[default]
+    Filled,
+    /// A colored border and text, with no background.
+    Outline,
+    /// Colored text, with no background or border.
+    Ghost,
+}
+
+impl ButtonVariant {
+    /// Gets the name of the button variant.
+    pub fn variant_name(&self) -> &'static str {
+        match *self {
+            Self::Filled => "filled",
+            Self::Outline => "outline",
+            Self::Ghost => "ghost",
+        }
+    }
+}
+
+/// The type of button element.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ButtonType {
+    /// A button with no default behavior.
+    #[default]
+    Button,
+    /// A button that submits its form.
+    Submit,
+    /// A button that resets its form.
+    Reset,
+}
+
+impl ButtonType {
+    /// Gets the HTML button element type corresponding to the current button type.
+    pub fn html_button_type(&self) -> &'static str {
+        match *self {
+            Self::Button => "button",
+            Self::Submit => "submit",
+            Self::Reset => "reset",
+        }
+    }
+}
+
+/// A click event callback that returns a future.
+type AsyncCallback = Box<dyn Fn() -> Pin<Box<dyn Future<Output = ()>>> + 'static>;
+
 /// An abstraction over a button's click event callback.
 #[derive(Default)]
-pub struct OnClickCallback(Option<Box<dyn Fn() + 'static>>);
+pub struct OnClickCallback(Option<ClickHandler>);
+
+/// The kinds of click event callbacks.
+enum ClickHandler {
+    /// A callback that finishes when it returns.
+    Sync(Box<dyn Fn() + 'static>),
+    /// A callback that finishes when its future resolves.
+    Async(AsyncCallback),
+}
 
 impl OnClickCallback {
-    /// Call the callback.
-    fn call(&self) {
-        if let Some(callback) = &self.0 {
-            (**callback)()
+    /// Creates a callback that returns a future. A button with this callback
+    /// shows that it is loading, and cannot be clicked again, until the future
+    /// resolves.
+    pub fn new_async<F, Fut>(callback: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self(Some(ClickHandler::Async(Box::new(move || {
+            Box::pin(callback())
+        }))))
+    }
+
+    /// Call the callback, returning a future to await if the callback is
+    /// asynchronous.
+    fn call(&self) -> Option<Pin<Box<dyn Future<Output = ()>>>> {
+        match &self.0 {
+            Some(ClickHandler::Sync(callback)) => {
+                callback();
+                None
+            }
+            Some(ClickHandler::Async(callback)) => Some(callback()),
+            None => None,
         }
     }
 }
@@ -45,7 +147,7 @@ where
     F: Fn() + 'static,
 {
     fn from(value: F) -> Self {
-        Self(Some(Box::new(value)))
+        Self(Some(ClickHandler::Sync(Box::new(value))))
     }
 }
 
@@ -54,45 +156,145 @@ where
     F: Fn() + 'static,
 {
     fn from(value: Option<F>) -> Self {
-        Self(match value {
-            Some(callback) => Some(Box::new(callback)),
-            None => None,
-        })
+        Self(value.map(|callback| ClickHandler::Sync(Box::new(callback))))
     }
 }
 
-/// A button element.
+/// A button element. When given an `href`, the button is rendered as a link
+/// styled as a button.
 #[component]
 pub fn Button(
     cx: Scope,
     /// The text on the button.
-    #[prop(into)]
+    #[prop(into, optional)]
     text: MaybeSignal<String>,
-    /// The button click callback.
+    /// The button click callback. Use [`OnClickCallback::new_async`] for a
+    /// callback that returns a future.
     #[prop(into, optional)]
     on_click: OnClickCallback,
     /// The button style.
     #[prop(into, optional)]
     style: MaybeSignal<ButtonStyle>,
+    /// The button size.
+    #[prop(into, optional)]
+    size: MaybeSignal<ButtonSize>,
+    /// How the button is filled in with the color of its style.
+    #[prop(into, optional)]
+    variant: MaybeSignal<ButtonVariant>,
+    /// The button type.
+    #[prop(optional)]
+    button_type: ButtonType,
+    /// The URL to link to, rendering the button as a link.
+    #[prop(into, optional)]
+    href: Option<MaybeSignal<String>>,
+    /// An icon shown before the button's content.
+    #[prop(into, optional)]
+    leading_icon: Option<View>,
+    /// An icon shown after the button's content.
+    #[prop(into, optional)]
+    trailing_icon: Option<View>,
+    /// An accessible label for the button. Buttons with only an icon must be
+    /// given a label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// Whether the button fills the width of its container.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    full_width: MaybeSignal<bool>,
     /// Whether the button is disabled.
     #[prop(into, optional, default = MaybeSignal::Static(false))]
     disabled: MaybeSignal<bool>,
+    /// Whether the button is loading. A loading button shows a spinner and
+    /// cannot be clicked.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    loading: MaybeSignal<bool>,
+    /// The content of the button, shown after its text.
+    #[prop(optional)]
+    children: Option<Children>,
 ) -> impl IntoView {
+    let (pending, set_pending) = create_signal(cx, false);
+    let busy = move || loading() || pending();
+
+    let children = children.map(|children| children(cx));
+    let has_icon = leading_icon.is_some() || trailing_icon.is_some();
+    let has_children = children.is_some();
+    let icon_only = {
+        let text = text.clone();
+        move || has_icon && !has_children && text.with(|text| text.is_empty())
+    };
+
     let button_class = move || {
         classes!(
             "leptonic-button",
-            format!("leptonic-button-{}", style.with(|s| s.style_name()))
+            format!("leptonic-button-{}", style.with(|s| s.style_name())),
+            format!("leptonic-button-{}", size.with(|s| s.size_name())),
+            format!("leptonic-button-{}", variant.with(|v| v.variant_name())),
+            icon_only().then_some("leptonic-button-icon-only"),
+            full_width().then_some("leptonic-button-full-width"),
+            disabled().then_some("leptonic-button-disabled"),
+            busy().then_some("leptonic-button-loading")
         )
     };
+    let on_click = move |ev: web_sys::MouseEvent| {
+        if disabled() || busy() {
+            ev.prevent_default();
+            return;
+        }
 
-    view! { cx,
-        <button
-            on:click=move |_| on_click.call()
-            class=button_class
-            type="button"
-            disabled=disabled
-        >
-            {text}
-        </button>
+        if let Some(future) = on_click.call() {
+            set_pending(true);
+            spawn_local(async move {
+                future.await;
+                // The button may have been removed while the future was pending.
+                _ = set_pending.try_set(false);
+            });
+        }
+    };
+    let aria_label = move || label.with(|label| (!label.is_empty()).then(|| label.clone()));
+    let icon = |icon: Option<View>| {
+        icon.map(|icon| view! { cx, <span class="leptonic-button-icon" aria-hidden="true">{icon}</span> })
+    };
+    let content = view! { cx,
+        <span class="leptonic-button-content">
+            {icon(leading_icon)}
+            {move || text.with(|text| (!text.is_empty()).then(|| text.clone()))}
+            {children}
+            {icon(trailing_icon)}
+        </span>
+        {move || busy().then(|| view! { cx,
+            <span class="leptonic-button-spinner">
+                <Spinner size=SpinnerSize::Small />
+            </span>
+        })}
+    };
+
+    match href {
+        // Links cannot be disabled, so a disabled link has its URL removed.
+        Some(href) => view! { cx,
+            <a
+                on:click=on_click
+                class=button_class
+                href=move || (!disabled() && !busy()).then(|| href.get())
+                role=move || disabled().then_some("link")
+                aria-disabled=move || disabled().to_string()
+                aria-busy=move || busy().to_string()
+                aria-label=aria_label
+            >
+                {content}
+            </a>
+        }
+        .into_view(cx),
+        None => view! { cx,
+            <button
+                on:click=on_click
+                class=button_class
+                type=button_type.html_button_type()
+                disabled=move || disabled() || busy()
+                aria-busy=move || busy().to_string()
+                aria-label=aria_label
+            >
+                {content}
+            </button>
+        }
+        .into_view(cx),
     }
 }
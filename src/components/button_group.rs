@@ -0,0 +1,341 @@
+use super::button::*;
+use super::popover::*;
+use super::select::*;
+use crate::classes::*;
+use crate::position::*;
+use crate::util::*;
+use leptos::ev::KeyboardEvent;
+use leptos::html::Div;
+use leptos::*;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// A row of buttons attached to one another.
+#[component]
+pub fn ButtonGroup(
+    cx: Scope,
+    /// An accessible label for the group.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// Whether the buttons are stacked vertically.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    vertical: MaybeSignal<bool>,
+    /// The buttons in the group.
+    children: Children,
+) -> impl IntoView {
+    let class = move || {
+        classes!(
+            "leptonic-button-group",
+            vertical().then_some("leptonic-button-group-vertical")
+        )
+    };
+
+    view! { cx,
+        <div
+            class=class
+            role="group"
+            aria-label=move || label.with(|label| (!label.is_empty()).then(|| label.clone()))
+        >
+            {children(cx)}
+        </div>
+    }
+}
+
+/// A row of attached buttons for picking one of a few values.
+#[component]
+pub fn SegmentedControl<T>(
+    cx: Scope,
+    /// Segmented control state.
+    state: ReadSignal<T>,
+    /// Segmented control state setter.
+    set_state: WriteSignal<T>,
+    /// The options to choose from. Option groups are ignored.
+    #[prop(into)]
+    options: MaybeSignal<Vec<SelectOption<T>>>,
+    /// An accessible label for the control.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// The style of the options. The selected option is filled in, and the
+    /// others are outlined.
+    #[prop(into, optional)]
+    style: MaybeSignal<ButtonStyle>,
+    /// The size of the options.
+    #[prop(into, optional)]
+    size: MaybeSignal<ButtonSize>,
+    /// Whether the segmented control is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let options = create_memo(cx, move |_| options());
+    let focus = RovingFocus::new(cx, new_id(), state, set_state, move || {
+        options.with(|opts| {
+            opts.iter()
+                .map(|opt| (opt.value.clone(), !disabled.get() && !opt.disabled))
+                .collect()
+        })
+    });
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let target = match ev.key().as_str() {
+            "ArrowLeft" | "ArrowRight" => focus.step((ev.key() == "ArrowRight") != is_rtl()),
+            "Home" => focus.first(),
+            "End" => focus.last(),
+            _ => return,
+        };
+
+        ev.prevent_default();
+
+        if let Some(index) = target {
+            focus.select(index);
+        }
+    };
+
+    let items = move || {
+        options.with(|opts| {
+            opts.iter()
+                .enumerate()
+                .map(|(index, opt)| {
+                    let checked = move || focus.selected_index() == Some(index);
+                    let option_class = move || {
+                        classes!(
+                            "leptonic-button",
+                            "leptonic-segment",
+                            format!("leptonic-button-{}", style.with(|s| s.style_name())),
+                            format!("leptonic-button-{}", size.with(|s| s.size_name())),
+                            if checked() {
+                                "leptonic-button-filled"
+                            } else {
+                                "leptonic-button-outline"
+                            },
+                            checked().then_some("leptonic-segment-selected")
+                        )
+                    };
+
+                    view! { cx,
+                        <button
+                            id=focus.option_id(index)
+                            class=option_class
+                            type="button"
+                            role="radio"
+                            tabindex=move || focus.tabindex(index)
+                            aria-checked=move || checked().to_string()
+                            disabled=move || !focus.is_enabled(index)
+                            on:click=move |_| focus.select(index)
+                        >
+                            {opt.label.clone()}
+                        </button>
+                    }
+                })
+                .collect_view(cx)
+        })
+    };
+
+    view! { cx,
+        <div
+            class="leptonic-button-group leptonic-segmented-control"
+            role="radiogroup"
+            aria-label=move || label.with(|label| (!label.is_empty()).then(|| label.clone()))
+            on:keydown=on_keydown
+        >
+            {items}
+        </div>
+    }
+}
+
+/// A secondary action in a split button's menu.
+#[derive(Clone)]
+pub struct SplitButtonAction {
+    /// The text on the menu item.
+    pub text: String,
+    /// Whether the action is disabled.
+    pub disabled: bool,
+    /// The menu item click callback.
+    pub on_click: Rc<dyn Fn()>,
+}
+
+impl SplitButtonAction {
+    /// Creates a new action.
+    pub fn new(text: impl Into<String>, on_click: impl Fn() + 'static) -> Self {
+        Self {
+            text: text.into(),
+            disabled: false,
+            on_click: Rc::new(on_click),
+        }
+    }
+
+    /// Sets whether the action is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// A button with a primary action, attached to a button that opens a menu of
+/// secondary actions.
+#[component]
+pub fn SplitButton(
+    cx: Scope,
+    /// The text on the primary button.
+    #[prop(into)]
+    text: MaybeSignal<String>,
+    /// The primary button click callback.
+    #[prop(into, optional)]
+    on_click: OnClickCallback,
+    /// The secondary actions shown in the menu.
+    #[prop(into)]
+    actions: MaybeSignal<Vec<SplitButtonAction>>,
+    /// An accessible label for the menu button.
+    #[prop(into, optional, default = MaybeSignal::Static("More actions".to_owned()))]
+    menu_label: MaybeSignal<String>,
+    /// The button style.
+    #[prop(into, optional)]
+    style: MaybeSignal<ButtonStyle>,
+    /// The button size.
+    #[prop(into, optional)]
+    size: MaybeSignal<ButtonSize>,
+    /// Whether the split button is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    let menu_id = format!("{}-menu", new_id());
+    let (open, set_open) = create_signal(cx, false);
+    let toggle_ref = create_node_ref::<html::Button>(cx);
+    let menu_ref = create_node_ref::<Div>(cx);
+
+    use_floating_trigger(
+        cx,
+        toggle_ref,
+        menu_ref,
+        open,
+        set_open,
+        MaybeSignal::Static(FloatingTrigger::Click),
+        MaybeSignal::Static(Duration::ZERO),
+    );
+
+    // Move focus into the menu when it is opened.
+    create_effect(cx, move |_| {
+        if let (true, Some(menu)) = (open(), menu_ref.get()) {
+            request_animation_frame(move || {
+                if let Some(first) = focusable_elements(&menu).first() {
+                    _ = first.focus();
+                }
+            });
+        }
+    });
+
+    let close_menu = move || {
+        set_open(false);
+        if let Some(toggle) = toggle_ref.get_untracked() {
+            _ = toggle.focus();
+        }
+    };
+
+    let on_menu_keydown = move |ev: KeyboardEvent| {
+        let Some(menu) = menu_ref.get_untracked() else {
+            return;
+        };
+        let items = focusable_elements(&menu);
+        let current = document().active_element().and_then(|active| {
+            items
+                .iter()
+                .position(|item| AsRef::<web_sys::Element>::as_ref(item) == &active)
+        });
+        let target = match ev.key().as_str() {
+            "ArrowDown" => step_enabled_index(items.len(), current, true, true, |_| true),
+            "ArrowUp" => step_enabled_index(items.len(), current, false, true, |_| true),
+            "Home" => first_enabled_index(items.len(), |_| true),
+            "End" => last_enabled_index(items.len(), |_| true),
+            "Tab" => {
+                set_open(false);
+                return;
+            }
+            _ => return,
+        };
+
+        ev.prevent_default();
+
+        if let Some(item) = target.and_then(|index| items.get(index)) {
+            _ = item.focus();
+        }
+    };
+
+    let menu = {
+        let menu_id = menu_id.clone();
+        move || {
+            let items = actions
+                .get()
+                .into_iter()
+                .map(|action| {
+                    let on_click = Rc::clone(&action.on_click);
+                    view! { cx,
+                        <button
+                            class="leptonic-split-button-menu-item"
+                            type="button"
+                            role="menuitem"
+                            disabled=action.disabled
+                            on:click=move |_| {
+                                close_menu();
+                                on_click();
+                            }
+                        >
+                            {action.text}
+                        </button>
+                    }
+                })
+                .collect_view(cx);
+
+            view! { cx,
+                <div
+                    id=menu_id.clone()
+                    class="leptonic-split-button-menu"
+                    role="menu"
+                    node_ref=menu_ref
+                    on:keydown=on_menu_keydown
+                >
+                    {items}
+                </div>
+            }
+        }
+    };
+
+    floating_layer(
+        cx,
+        toggle_ref,
+        menu_ref,
+        open,
+        MaybeSignal::Static(Placement::BOTTOM_END),
+        PositionOptions::default().offset(4.0),
+        menu,
+    );
+
+    let toggle_class = move || {
+        classes!(
+            "leptonic-button",
+            "leptonic-split-button-toggle",
+            format!("leptonic-button-{}", style.with(|s| s.style_name())),
+            format!("leptonic-button-{}", size.with(|s| s.size_name())),
+            "leptonic-button-filled"
+        )
+    };
+
+    view! { cx,
+        <div class="leptonic-button-group leptonic-split-button" role="group">
+            <Button text on_click style size disabled />
+            <button
+                class=toggle_class
+                type="button"
+                aria-label=menu_label
+                aria-haspopup="menu"
+                aria-expanded=move || open().to_string()
+                aria-controls=menu_id
+                disabled=disabled
+                node_ref=toggle_ref
+            >
+                <span class="leptonic-split-button-icon" aria-hidden="true"></span>
+            </button>
+        </div>
+    }
+}
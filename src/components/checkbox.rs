@@ -2,24 +2,99 @@ use crate::classes::*;
 use crate::util::*;
 use leptos::*;
 
-/// A checkbox element.
-#[component]
-pub fn Checkbox(
+/// The state of a checkbox that may be partially checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum CheckboxState {
+    /// The checkbox is not checked.
+    #[default]
+    Unchecked,
+    /// The checkbox is checked.
+    Checked,
+    /// The checkbox is neither checked nor unchecked, such as when it
+    /// represents a partially selected group.
+    Indeterminate,
+}
+
+impl CheckboxState {
+    /// Gets the state of a checkbox representing a group of items, of which
+    /// `checked` out of `total` are checked.
+    ///
+    /// ```
+    /// # use leptonic::CheckboxState;
+    /// assert_eq!(CheckboxState::from_counts(0, 3), CheckboxState::Unchecked);
+    /// assert_eq!(CheckboxState::from_counts(2, 3), CheckboxState::Indeterminate);
+    /// assert_eq!(CheckboxState::from_counts(3, 3), CheckboxState::Checked);
+    /// assert_eq!(CheckboxState::from_counts(0, 0), CheckboxState::Unchecked);
+    /// ```
+    pub fn from_counts(checked: usize, total: usize) -> Self {
+        match checked {
+            0 => Self::Unchecked,
+            checked if checked == total => Self::Checked,
+            _ => Self::Indeterminate,
+        }
+    }
+
+    /// Gets the name of the state.
+    pub fn state_name(&self) -> &'static str {
+        match *self {
+            Self::Unchecked => "unchecked",
+            Self::Checked => "checked",
+            Self::Indeterminate => "indeterminate",
+        }
+    }
+
+    /// Whether the checkbox is checked.
+    pub fn is_checked(&self) -> bool {
+        matches!(*self, Self::Checked)
+    }
+
+    /// Gets the state after the checkbox is clicked. An indeterminate checkbox
+    /// becomes checked.
+    ///
+    /// ```
+    /// # use leptonic::CheckboxState;
+    /// assert_eq!(CheckboxState::Unchecked.toggled(), CheckboxState::Checked);
+    /// assert_eq!(CheckboxState::Checked.toggled(), CheckboxState::Unchecked);
+    /// assert_eq!(CheckboxState::Indeterminate.toggled(), CheckboxState::Checked);
+    /// ```
+    pub fn toggled(&self) -> Self {
+        match *self {
+            Self::Checked => Self::Unchecked,
+            Self::Unchecked | Self::Indeterminate => Self::Checked,
+        }
+    }
+}
+
+impl From<bool> for CheckboxState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            Self::Checked
+        } else {
+            Self::Unchecked
+        }
+    }
+}
+
+impl From<Option<bool>> for CheckboxState {
+    fn from(checked: Option<bool>) -> Self {
+        checked.map_or(Self::Indeterminate, Self::from)
+    }
+}
+
+/// Renders a labelled checkbox. `on_toggle` is called with the new checked
+/// state when the checkbox is clicked.
+pub(crate) fn checkbox_view(
     cx: Scope,
-    /// Checkbox state.
-    state: ReadSignal<bool>,
-    /// Checkbox state setter.
-    set_state: WriteSignal<bool>,
-    /// The checkbox label.
-    #[prop(into, optional)]
+    checked: MaybeSignal<bool>,
+    indeterminate: MaybeSignal<bool>,
+    on_toggle: impl Fn(bool) + 'static,
     label: MaybeSignal<String>,
-    /// Whether the checkbox is disabled.
-    #[prop(into, optional, default = MaybeSignal::Static(false))]
     disabled: MaybeSignal<bool>,
 ) -> impl IntoView {
     let label_class = move || {
         classes!(
             "leptonic-checkbox",
+            indeterminate().then_some("leptonic-checkbox-indeterminate"),
             disabled().then_some("leptonic-checkbox-disabled")
         )
     };
@@ -29,8 +104,9 @@ pub fn Checkbox(
             <label class=label_class>
                 <span class="leptonic-checkbox-label">{label}</span>
                 <input
-                    prop:checked=state
-                    on:click=move |ev| set_state(checkbox_checked(ev))
+                    prop:checked=checked
+                    prop:indeterminate=indeterminate
+                    on:click=move |ev| on_toggle(checkbox_checked(ev))
                     type="checkbox"
                     {disabled}
                     class="leptonic-checkbox-input"
@@ -43,3 +119,229 @@ pub fn Checkbox(
         </div>
     }
 }
+
+/// A checkbox element.
+#[component]
+pub fn Checkbox(
+    cx: Scope,
+    /// Checkbox state.
+    state: ReadSignal<bool>,
+    /// Checkbox state setter.
+    set_state: WriteSignal<bool>,
+    /// The checkbox label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// Whether the checkbox is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    checkbox_view(
+        cx,
+        state.into(),
+        MaybeSignal::Static(false),
+        set_state,
+        label,
+        disabled,
+    )
+}
+
+/// A checkbox element that may be partially checked. Clicking an
+/// indeterminate checkbox checks it.
+#[component]
+pub fn TriStateCheckbox(
+    cx: Scope,
+    /// Checkbox state.
+    state: ReadSignal<CheckboxState>,
+    /// Checkbox state setter.
+    set_state: WriteSignal<CheckboxState>,
+    /// The checkbox label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// Whether the checkbox is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    checkbox_view(
+        cx,
+        Signal::derive(cx, move || state.with(CheckboxState::is_checked)).into(),
+        Signal::derive(cx, move || state() == CheckboxState::Indeterminate).into(),
+        move |checked| set_state(checked.into()),
+        label,
+        disabled,
+    )
+}
+
+/// Adds a value to or removes a value from a list of checked values.
+pub(crate) fn set_checked<T: PartialEq>(values: &mut Vec<T>, value: T, checked: bool) {
+    let position = values.iter().position(|other| *other == value);
+
+    match (checked, position) {
+        (true, None) => values.push(value),
+        (false, Some(index)) => {
+            values.remove(index);
+        }
+        _ => {}
+    }
+}
+
+/// An option in a checkbox group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckboxOption<T> {
+    /// The value of the option.
+    pub value: T,
+    /// The option label.
+    pub label: String,
+    /// Whether the option is disabled.
+    pub disabled: bool,
+}
+
+impl<T> CheckboxOption<T> {
+    /// Creates a new option.
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    /// Sets whether the option is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// A group of checkboxes, any number of which can be checked. The group's
+/// label is shown on a parent checkbox that checks or unchecks every option,
+/// and is indeterminate while only some of them are checked.
+#[component]
+pub fn CheckboxGroup<T>(
+    cx: Scope,
+    /// The values of the checked options.
+    state: ReadSignal<Vec<T>>,
+    /// Checked options setter.
+    set_state: WriteSignal<Vec<T>>,
+    /// The options to choose from.
+    #[prop(into)]
+    options: MaybeSignal<Vec<CheckboxOption<T>>>,
+    /// The group label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// Whether to show the parent checkbox. If not, the label is shown on its
+    /// own.
+    #[prop(into, optional, default = MaybeSignal::Static(true))]
+    select_all: MaybeSignal<bool>,
+    /// Whether the checkbox group is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let label_id = format!("{}-label", new_id());
+    let options = create_memo(cx, move |_| options());
+
+    // The parent checkbox only accounts for options that can be changed.
+    let parent_state = create_memo(cx, move |_| {
+        let (total, checked) = options.with(|opts| {
+            state.with(|values| {
+                opts.iter()
+                    .filter(|opt| !opt.disabled)
+                    .fold((0, 0), |(total, checked), opt| {
+                        (
+                            total + 1,
+                            checked + usize::from(values.contains(&opt.value)),
+                        )
+                    })
+            })
+        });
+
+        CheckboxState::from_counts(checked, total)
+    });
+    let set_all = move |checked: bool| {
+        options.with_untracked(|opts| {
+            set_state.update(|values| {
+                for opt in opts.iter().filter(|opt| !opt.disabled) {
+                    set_checked(values, opt.value.clone(), checked);
+                }
+            });
+        });
+    };
+    let set_option = move |value: T, checked: bool| {
+        set_state.update(|values| set_checked(values, value, checked))
+    };
+
+    let parent = {
+        let label_id = label_id.clone();
+        move || {
+            let label = label.clone();
+
+            if select_all() {
+                let all_disabled = Signal::derive(cx, move || {
+                    disabled() || options.with(|opts| opts.iter().all(|opt| opt.disabled))
+                });
+
+                view! { cx,
+                    <div id=label_id.clone() class="leptonic-checkbox-group-parent">
+                        {checkbox_view(
+                            cx,
+                            Signal::derive(cx, move || parent_state().is_checked()).into(),
+                            Signal::derive(cx, move || {
+                                parent_state() == CheckboxState::Indeterminate
+                            })
+                            .into(),
+                            move |_| set_all(parent_state.get_untracked().toggled().is_checked()),
+                            label,
+                            all_disabled.into(),
+                        )}
+                    </div>
+                }
+                .into_view(cx)
+            } else {
+                view! { cx,
+                    <span id=label_id.clone() class="leptonic-checkbox-group-label">{label}</span>
+                }
+                .into_view(cx)
+            }
+        }
+    };
+
+    let items = move || {
+        options.with(|opts| {
+            opts.iter()
+                .map(|opt| {
+                    let checked = {
+                        let value = opt.value.clone();
+                        Signal::derive(cx, move || state.with(|values| values.contains(&value)))
+                    };
+                    let value = opt.value.clone();
+                    let option_disabled = opt.disabled;
+
+                    checkbox_view(
+                        cx,
+                        checked.into(),
+                        MaybeSignal::Static(false),
+                        move |checked| set_option(value.clone(), checked),
+                        MaybeSignal::Static(opt.label.clone()),
+                        Signal::derive(cx, move || disabled() || option_disabled).into(),
+                    )
+                })
+                .collect_view(cx)
+        })
+    };
+
+    let group_class = move || {
+        classes!(
+            "leptonic-checkbox-group",
+            disabled().then_some("leptonic-checkbox-group-disabled")
+        )
+    };
+
+    view! { cx,
+        <div class=group_class role="group" aria-labelledby=label_id>
+            {parent}
+            <div class="leptonic-checkbox-group-options">{items}</div>
+        </div>
+    }
+}
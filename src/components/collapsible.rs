@@ -0,0 +1,244 @@
+use crate::classes::*;
+use crate::util::*;
+use leptos::ev::KeyboardEvent;
+use leptos::html::Div;
+use leptos::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+/// Renders a section with a header that expands and collapses its content.
+fn collapsible_section(
+    cx: Scope,
+    open: Signal<bool>,
+    toggle: impl Fn() + 'static,
+    title: MaybeSignal<String>,
+    disabled: MaybeSignal<bool>,
+    children: Children,
+) -> impl IntoView {
+    let id = new_id();
+    let header_id = format!("{id}-header");
+    let content_id = format!("{id}-content");
+
+    let class = move || {
+        classes!(
+            "leptonic-collapsible",
+            open().then_some("leptonic-collapsible-open"),
+            disabled().then_some("leptonic-collapsible-disabled")
+        )
+    };
+
+    view! { cx,
+        <div class=class>
+            <button
+                id=header_id.clone()
+                class="leptonic-collapsible-header"
+                type="button"
+                disabled=disabled
+                aria-expanded=move || open().to_string()
+                aria-controls=content_id.clone()
+                on:click=move |_| {
+                    if !disabled.get() {
+                        toggle();
+                    }
+                }
+            >
+                <span class="leptonic-collapsible-title">{title}</span>
+                <span class="leptonic-collapsible-icon" aria-hidden="true"></span>
+            </button>
+            <div
+                id=content_id
+                class="leptonic-collapsible-content"
+                role="region"
+                aria-labelledby=header_id
+                aria-hidden=move || (!open()).to_string()
+            >
+                <div class="leptonic-collapsible-body">{children(cx)}</div>
+            </div>
+        </div>
+    }
+}
+
+/// A section with a header that expands and collapses its content.
+#[component]
+pub fn Collapsible(
+    cx: Scope,
+    /// Whether the section is expanded.
+    state: ReadSignal<bool>,
+    /// Expanded state setter.
+    set_state: WriteSignal<bool>,
+    /// The section title, shown in its header.
+    #[prop(into)]
+    title: MaybeSignal<String>,
+    /// Whether the section is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+    /// The section content.
+    children: Children,
+) -> impl IntoView {
+    collapsible_section(
+        cx,
+        state.into(),
+        move || set_state.update(|open| *open = !*open),
+        title,
+        disabled,
+        children,
+    )
+}
+
+/// How many items of an accordion may be expanded at once.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum AccordionMode {
+    /// Expanding an item collapses the others.
+    #[default]
+    Single,
+    /// Any number of items may be expanded.
+    Multiple,
+}
+
+impl AccordionMode {
+    /// Gets the name of the mode.
+    pub fn mode_name(&self) -> &'static str {
+        match *self {
+            Self::Single => "single",
+            Self::Multiple => "multiple",
+        }
+    }
+}
+
+/// State shared between an accordion and its items.
+#[derive(Clone, Copy)]
+struct AccordionContext {
+    /// The keys of the expanded items.
+    state: ReadSignal<Vec<String>>,
+    /// Expanded items setter.
+    set_state: WriteSignal<Vec<String>>,
+    /// How many items may be expanded at once.
+    mode: MaybeSignal<AccordionMode>,
+}
+
+/// A stack of collapsible sections. Place an [`AccordionItem`] within it for
+/// each section.
+#[component]
+pub fn Accordion(
+    cx: Scope,
+    /// The keys of the expanded items.
+    state: ReadSignal<Vec<String>>,
+    /// Expanded items setter.
+    set_state: WriteSignal<Vec<String>>,
+    /// How many items may be expanded at once.
+    #[prop(into, optional)]
+    mode: MaybeSignal<AccordionMode>,
+    /// The accordion items.
+    children: Children,
+) -> impl IntoView {
+    provide_context(
+        cx,
+        AccordionContext {
+            state,
+            set_state,
+            mode,
+        },
+    );
+
+    let accordion_ref = create_node_ref::<Div>(cx);
+
+    // Only keep the first expanded item when switching to single mode.
+    create_effect(cx, move |_| {
+        if mode() == AccordionMode::Single && state.with_untracked(|expanded| expanded.len() > 1) {
+            set_state.update(|expanded| expanded.truncate(1));
+        }
+    });
+
+    // Move focus between the item headers with the arrow, home, and end keys.
+    let on_keydown = move |ev: KeyboardEvent| {
+        let Some(accordion) = accordion_ref.get_untracked() else {
+            return;
+        };
+        let Ok(nodes) = accordion.query_selector_all(
+            ":scope > .leptonic-collapsible > .leptonic-collapsible-header:not(:disabled)",
+        ) else {
+            return;
+        };
+        let headers = (0..nodes.length())
+            .filter_map(|index| nodes.item(index))
+            .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+            .collect::<Vec<_>>();
+        let current = document().active_element().and_then(|active| {
+            headers
+                .iter()
+                .position(|header| *header.unchecked_ref::<web_sys::Element>() == active)
+        });
+
+        if current.is_none() {
+            return;
+        }
+
+        let target = match ev.key().as_str() {
+            "ArrowDown" => step_enabled_index(headers.len(), current, true, true, |_| true),
+            "ArrowUp" => step_enabled_index(headers.len(), current, false, true, |_| true),
+            "Home" => first_enabled_index(headers.len(), |_| true),
+            "End" => last_enabled_index(headers.len(), |_| true),
+            _ => return,
+        };
+
+        ev.prevent_default();
+
+        if let Some(header) = target.and_then(|index| headers.get(index)) {
+            _ = header.focus();
+        }
+    };
+
+    view! { cx,
+        <div
+            class=move || format!("leptonic-accordion leptonic-accordion-{}", mode().mode_name())
+            node_ref=accordion_ref
+            on:keydown=on_keydown
+        >
+            {children(cx)}
+        </div>
+    }
+}
+
+/// A collapsible section within an [`Accordion`].
+#[component]
+pub fn AccordionItem(
+    cx: Scope,
+    /// The key identifying the item.
+    #[prop(into)]
+    key: String,
+    /// The item title, shown in its header.
+    #[prop(into)]
+    title: MaybeSignal<String>,
+    /// Whether the item is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+    /// The item content.
+    children: Children,
+) -> impl IntoView {
+    let AccordionContext {
+        state,
+        set_state,
+        mode,
+    } = use_context::<AccordionContext>(cx)
+        .expect("accordion items must be placed within `Accordion`");
+
+    let open = {
+        let key = key.clone();
+        Signal::derive(cx, move || state.with(|expanded| expanded.contains(&key)))
+    };
+    let toggle = move || {
+        set_state.update(|expanded| {
+            if let Some(index) = expanded.iter().position(|expanded| *expanded == key) {
+                expanded.remove(index);
+            } else {
+                if mode.get_untracked() == AccordionMode::Single {
+                    expanded.clear();
+                }
+
+                expanded.push(key.clone());
+            }
+        });
+    };
+
+    collapsible_section(cx, open, toggle, title, disabled, children)
+}
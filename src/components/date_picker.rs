@@ -0,0 +1,421 @@
+use super::error::*;
+use crate::classes::*;
+use crate::date::*;
+use crate::util::*;
+use chrono::{Datelike, NaiveDate, Weekday};
+use leptos::ev::KeyboardEvent;
+use leptos::html::{Div, Input};
+use leptos::*;
+use leptos_use::on_click_outside;
+
+/// The default format of typed dates.
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// An abstraction over a predicate deciding which dates are disabled.
+#[derive(Default)]
+pub struct DateFilter(Option<Box<dyn Fn(NaiveDate) -> bool + 'static>>);
+
+impl DateFilter {
+    /// Determines whether the date is disabled.
+    pub(crate) fn is_disabled(&self, date: NaiveDate) -> bool {
+        match &self.0 {
+            Some(predicate) => (**predicate)(date),
+            None => false,
+        }
+    }
+}
+
+impl<F> From<F> for DateFilter
+where
+    F: Fn(NaiveDate) -> bool + 'static,
+{
+    fn from(value: F) -> Self {
+        Self(Some(Box::new(value)))
+    }
+}
+
+impl<F> From<Option<F>> for DateFilter
+where
+    F: Fn(NaiveDate) -> bool + 'static,
+{
+    fn from(value: Option<F>) -> Self {
+        Self(match value {
+            Some(predicate) => Some(Box::new(predicate)),
+            None => None,
+        })
+    }
+}
+
+/// Renders the weekday headers and weeks of a month's calendar grid. Each
+/// table cell is rendered by `day`, which is given the date and the month
+/// being shown.
+pub(crate) fn calendar_grid<M, D, V, K>(
+    cx: Scope,
+    month: M,
+    first_day_of_week: MaybeSignal<Weekday>,
+    day: D,
+    on_keydown: K,
+) -> impl IntoView
+where
+    M: Fn() -> NaiveDate + 'static,
+    D: Fn(NaiveDate, NaiveDate) -> V + 'static,
+    V: IntoView,
+    K: Fn(KeyboardEvent) + 'static,
+{
+    let weekday_headers = move || {
+        week_days(first_day_of_week())
+            .into_iter()
+            .map(|weekday| {
+                view! { cx,
+                    <th class="leptonic-date-picker-weekday" scope="col" abbr=weekday.to_string()>
+                        {weekday_abbreviation(weekday)}
+                    </th>
+                }
+            })
+            .collect_view(cx)
+    };
+    let weeks = move || {
+        let month = month();
+        calendar_days(month, first_day_of_week())
+            .chunks(7)
+            .map(|week| {
+                let days = week.iter().map(|&date| day(date, month)).collect_view(cx);
+
+                view! { cx, <tr>{days}</tr> }
+            })
+            .collect_view(cx)
+    };
+
+    view! { cx,
+        <table class="leptonic-date-picker-calendar" role="grid" on:keydown=on_keydown>
+            <thead>
+                <tr>{weekday_headers}</tr>
+            </thead>
+            <tbody>{weeks}</tbody>
+        </table>
+    }
+}
+
+/// A date picker element.
+#[component]
+pub fn DatePicker(
+    cx: Scope,
+    /// Date picker state.
+    state: ReadSignal<Option<NaiveDate>>,
+    /// Date picker state setter.
+    set_state: WriteSignal<Option<NaiveDate>>,
+    /// Date picker label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// Date picker placeholder text.
+    #[prop(into, optional)]
+    placeholder: MaybeSignal<String>,
+    /// The format of typed dates, in `chrono` format syntax.
+    #[prop(into, optional, default = MaybeSignal::Static(DEFAULT_DATE_FORMAT.to_owned()))]
+    format: MaybeSignal<String>,
+    /// The earliest date that can be chosen.
+    #[prop(into, optional)]
+    min: MaybeSignal<Option<NaiveDate>>,
+    /// The latest date that can be chosen.
+    #[prop(into, optional)]
+    max: MaybeSignal<Option<NaiveDate>>,
+    /// A predicate deciding which dates are disabled.
+    #[prop(into, optional)]
+    disabled_dates: DateFilter,
+    /// The day each week of the calendar starts on.
+    #[prop(into, optional, default = MaybeSignal::Static(Weekday::Sun))]
+    first_day_of_week: MaybeSignal<Weekday>,
+    /// Whether the date picker is required.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    required: MaybeSignal<bool>,
+    /// Whether the date picker is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+    /// An optional error message.
+    #[prop(into, optional)]
+    error: MaybeSignal<Option<String>>,
+) -> impl IntoView {
+    let id = new_id();
+    let popup_id = format!("{id}-popup");
+    let day_id = {
+        let id = id.clone();
+        move |date: NaiveDate| format!("{id}-{date}")
+    };
+
+    let format = create_memo(cx, move |_| format());
+    let disabled_dates = store_value(cx, disabled_dates);
+    let (open, set_open) = create_signal(cx, false);
+    let (text, set_text) = create_signal(cx, String::new());
+    let (focused, set_focused) = create_signal(cx, today());
+    let container_ref = create_node_ref::<Div>(cx);
+    let input_ref = create_node_ref::<Input>(cx);
+
+    let is_selectable = move |date: NaiveDate| {
+        date_in_range(date, min(), max()) && !disabled_dates.with_value(|f| f.is_disabled(date))
+    };
+    let format_date =
+        move |date: Option<NaiveDate>| date.map(|date| date.format(&format()).to_string());
+
+    // Keep the text in sync with the state, unless it already describes it.
+    create_effect(cx, move |_| {
+        let date = state();
+        let typed = text.with_untracked(|text| parse_date(text, &format()));
+
+        if typed != date {
+            set_text(format_date(date).unwrap_or_default());
+        }
+    });
+
+    let typed_invalid = move || {
+        text.with(|text| {
+            !text.trim().is_empty() && !parse_date(text, &format()).map_or(false, is_selectable)
+        })
+    };
+
+    let focus_day = {
+        let day_id = day_id.clone();
+        move |date: NaiveDate| {
+            set_focused(date);
+            focus_by_id(day_id(date));
+        }
+    };
+    let open_popup = {
+        let focus_day = focus_day.clone();
+        move || {
+            if !disabled() {
+                let date = state().unwrap_or_else(|| clamp_date(today(), min(), max()));
+                set_open(true);
+                focus_day(date);
+            }
+        }
+    };
+    let close_popup = move |refocus: bool| {
+        set_open(false);
+
+        if refocus {
+            if let Some(input) = input_ref.get_untracked() {
+                _ = input.focus();
+            }
+        }
+    };
+    let select_date = move |date: NaiveDate| {
+        if is_selectable(date) {
+            set_state(Some(date));
+            close_popup(true);
+        }
+    };
+
+    let on_input = move |ev| {
+        let value = input_event_value(&ev);
+
+        if value.trim().is_empty() {
+            set_state(None);
+        } else if let Some(date) = parse_date(&value, &format()) {
+            if is_selectable(date) {
+                set_state(Some(date));
+                set_focused(date);
+            }
+        }
+
+        set_text(value);
+    };
+    let on_input_keydown = {
+        let open_popup = open_popup.clone();
+        move |ev: KeyboardEvent| {
+            if ev.key() == "ArrowDown" && ev.alt_key() {
+                ev.prevent_default();
+                open_popup();
+            }
+        }
+    };
+    let on_grid_keydown = {
+        let focus_day = focus_day.clone();
+        move |ev: KeyboardEvent| {
+            let date = focused();
+            let target = match ev.key().as_str() {
+                "Enter" | " " => {
+                    ev.prevent_default();
+                    select_date(date);
+                    None
+                }
+                "Escape" => {
+                    ev.prevent_default();
+                    close_popup(true);
+                    None
+                }
+                key => {
+                    calendar_key_target(key, ev.shift_key(), is_rtl(), date, first_day_of_week())
+                        .map(|target| clamp_date(target, min(), max()))
+                }
+            };
+
+            if let Some(target) = target {
+                ev.prevent_default();
+                focus_day(target);
+            }
+        }
+    };
+
+    _ = on_click_outside(cx, container_ref, move |_| {
+        if open.get_untracked() {
+            close_popup(false);
+        }
+    });
+
+    // Only changes with the month, so that the grid is not rendered again
+    // each time another day is focused.
+    let view_month = create_memo(cx, move |_| month_start(focused()));
+    // Whether navigating by a number of months would leave the allowed range.
+    let nav_disabled = move |months: i32| {
+        let month = add_months(view_month(), months);
+
+        if months < 0 {
+            min().map_or(false, |min| month_end(month) < min)
+        } else {
+            max().map_or(false, |max| month_start(month) > max)
+        }
+    };
+    let nav_button = move |months: i32, aria_label: &'static str, icon_class: &'static str| {
+        view! { cx,
+            <button
+                class="leptonic-date-picker-nav"
+                type="button"
+                aria-label=aria_label
+                disabled=move || nav_disabled(months)
+                on:click=move |_| {
+                    set_focused(clamp_date(add_months(focused(), months), min(), max()));
+                }
+            >
+                <span class=icon_class></span>
+            </button>
+        }
+    };
+
+    let container_class = move || {
+        classes!(
+            "leptonic-date-picker-container",
+            disabled().then_some("leptonic-date-picker-container-disabled")
+        )
+    };
+    let field_class = {
+        let error = error.clone();
+        move || {
+            classes!(
+                "leptonic-date-picker-field",
+                open().then_some("leptonic-date-picker-field-open"),
+                (error().is_some() || typed_invalid())
+                    .then_some("leptonic-date-picker-field-invalid")
+            )
+        }
+    };
+
+    let popup = {
+        let popup_id = popup_id.clone();
+        move || {
+            open().then(|| {
+                let day_id = day_id.clone();
+                let day = move |date: NaiveDate, month: NaiveDate| {
+                    let selectable = is_selectable(date);
+                    let selected = move || state() == Some(date);
+                    let day_class = move || {
+                        classes!(
+                            "leptonic-date-picker-day",
+                            (date.month() != month.month())
+                                .then_some("leptonic-date-picker-day-outside"),
+                            (date == today()).then_some("leptonic-date-picker-day-today"),
+                            selected().then_some("leptonic-date-picker-day-selected"),
+                            (!selectable).then_some("leptonic-date-picker-day-disabled")
+                        )
+                    };
+
+                    view! { cx,
+                        <td role="gridcell" aria-selected=move || selected().to_string()>
+                            <button
+                                id=day_id(date)
+                                class=day_class
+                                type="button"
+                                tabindex=move || if focused() == date { "0" } else { "-1" }
+                                aria-label=day_label(date)
+                                aria-disabled=(!selectable).to_string()
+                                on:click=move |_| select_date(date)
+                                on:focus=move |_| set_focused(date)
+                            >
+                                {date.day()}
+                            </button>
+                        </td>
+                    }
+                };
+
+                view! { cx,
+                    <div
+                        id=popup_id.clone()
+                        class="leptonic-date-picker-popup"
+                        role="dialog"
+                        aria-modal="false"
+                        aria-label="Choose date"
+                    >
+                        <div class="leptonic-date-picker-header">
+                            <div class="leptonic-date-picker-header-group">
+                                {nav_button(-1, "Previous month", "leptonic-date-picker-prev-icon")}
+                                <span class="leptonic-date-picker-header-label" aria-live="polite">
+                                    {move || month_name(view_month())}
+                                </span>
+                                {nav_button(1, "Next month", "leptonic-date-picker-next-icon")}
+                            </div>
+                            <div class="leptonic-date-picker-header-group">
+                                {nav_button(-12, "Previous year", "leptonic-date-picker-prev-icon")}
+                                <span class="leptonic-date-picker-header-label" aria-live="polite">
+                                    {move || view_month().year()}
+                                </span>
+                                {nav_button(12, "Next year", "leptonic-date-picker-next-icon")}
+                            </div>
+                        </div>
+                        {calendar_grid(cx, view_month, first_day_of_week, day, on_grid_keydown.clone())}
+                    </div>
+                }
+            })
+        }
+    };
+
+    view! { cx,
+        <div class=container_class node_ref=container_ref>
+            <label for=id.clone() class="leptonic-date-picker-label">
+                {label}
+                <span class="leptonic-required-mark">{required().then_some(" *").unwrap_or_default()}</span>
+            </label>
+            <div class="leptonic-date-picker">
+                <div class=field_class>
+                    <input
+                        id=id
+                        class="leptonic-date-picker-input"
+                        type="text"
+                        autocomplete="off"
+                        placeholder=placeholder
+                        prop:value=text
+                        aria-invalid=move || typed_invalid().to_string()
+                        aria-required=move || required().to_string()
+                        disabled=disabled
+                        node_ref=input_ref
+                        on:input=on_input
+                        on:keydown=on_input_keydown
+                        on:blur=move |_| set_text(format_date(state()).unwrap_or_default())
+                    />
+                    <button
+                        class="leptonic-date-picker-toggle"
+                        type="button"
+                        aria-label="Choose date"
+                        aria-haspopup="dialog"
+                        aria-controls=popup_id
+                        aria-expanded=move || open().to_string()
+                        disabled=disabled
+                        on:click=move |_| if open() { close_popup(false) } else { open_popup() }
+                    >
+                        <span class="leptonic-date-picker-icon"></span>
+                    </button>
+                </div>
+                {popup}
+            </div>
+            <Error message=error size=ErrorSize::Small />
+        </div>
+    }
+}
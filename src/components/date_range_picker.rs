@@ -0,0 +1,450 @@
+use super::date_picker::*;
+use super::error::*;
+use crate::classes::*;
+use crate::date::*;
+use crate::util::*;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use leptos::ev::KeyboardEvent;
+use leptos::html::{Button, Div};
+use leptos::*;
+use leptos_use::on_click_outside;
+
+/// A named date range that can be chosen with a single click.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateRangePreset {
+    /// The preset label.
+    pub label: String,
+    /// The range of dates the preset chooses.
+    pub range: DateRange,
+}
+
+impl DateRangePreset {
+    /// Creates a new preset.
+    pub fn new(label: impl Into<String>, range: DateRange) -> Self {
+        Self {
+            label: label.into(),
+            range,
+        }
+    }
+
+    /// Creates a preset covering a number of days, ending today.
+    pub fn last_days(label: impl Into<String>, days: u32) -> Self {
+        let today = today();
+        let start = today - Duration::days(days.saturating_sub(1) as i64);
+        Self::new(label, DateRange::new(start, today))
+    }
+
+    /// Creates a preset covering the current month, up to and including today.
+    pub fn this_month(label: impl Into<String>) -> Self {
+        let today = today();
+        Self::new(label, DateRange::new(month_start(today), today))
+    }
+
+    /// Creates a preset covering the whole of the previous month.
+    pub fn last_month(label: impl Into<String>) -> Self {
+        let month = add_months(today(), -1);
+        Self::new(label, DateRange::new(month_start(month), month_end(month)))
+    }
+
+    /// Gets a list of commonly used presets.
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::last_days("Today", 1),
+            Self::last_days("Last 7 days", 7),
+            Self::last_days("Last 30 days", 30),
+            Self::this_month("This month"),
+            Self::last_month("Last month"),
+        ]
+    }
+}
+
+/// A date range picker element, showing two consecutive months.
+#[component]
+pub fn DateRangePicker(
+    cx: Scope,
+    /// Date range picker state.
+    state: ReadSignal<Option<DateRange>>,
+    /// Date range picker state setter.
+    set_state: WriteSignal<Option<DateRange>>,
+    /// Date range picker label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// Text shown when no range is chosen.
+    #[prop(into, optional)]
+    placeholder: MaybeSignal<String>,
+    /// The format dates are shown in, in `chrono` format syntax.
+    #[prop(into, optional, default = MaybeSignal::Static(DEFAULT_DATE_FORMAT.to_owned()))]
+    format: MaybeSignal<String>,
+    /// The earliest date that can be chosen.
+    #[prop(into, optional)]
+    min: MaybeSignal<Option<NaiveDate>>,
+    /// The latest date that can be chosen.
+    #[prop(into, optional)]
+    max: MaybeSignal<Option<NaiveDate>>,
+    /// A predicate deciding which dates are disabled.
+    #[prop(into, optional)]
+    disabled_dates: DateFilter,
+    /// The day each week of the calendar starts on.
+    #[prop(into, optional, default = MaybeSignal::Static(Weekday::Sun))]
+    first_day_of_week: MaybeSignal<Weekday>,
+    /// Ranges that can be chosen with a single click.
+    #[prop(into, optional)]
+    presets: MaybeSignal<Vec<DateRangePreset>>,
+    /// Whether the date range picker is required.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    required: MaybeSignal<bool>,
+    /// Whether the date range picker is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+    /// An optional error message.
+    #[prop(into, optional)]
+    error: MaybeSignal<Option<String>>,
+) -> impl IntoView {
+    let id = new_id();
+    let popup_id = format!("{id}-popup");
+    let day_id = {
+        let id = id.clone();
+        move |date: NaiveDate| format!("{id}-{date}")
+    };
+
+    let presets = create_memo(cx, move |_| presets());
+    let disabled_dates = store_value(cx, disabled_dates);
+    let (open, set_open) = create_signal(cx, false);
+    let (focused, set_focused) = create_signal(cx, today());
+    let (first_month, set_first_month) = create_signal(cx, month_start(today()));
+    let (anchor, set_anchor) = create_signal(cx, None::<NaiveDate>);
+    let (hovered, set_hovered) = create_signal(cx, None::<NaiveDate>);
+    let container_ref = create_node_ref::<Div>(cx);
+    let button_ref = create_node_ref::<Button>(cx);
+
+    let is_selectable = move |date: NaiveDate| {
+        date_in_range(date, min(), max()) && !disabled_dates.with_value(|f| f.is_disabled(date))
+    };
+    // Ranges can only be chosen if every date in them can be.
+    let is_range_selectable = move |range: DateRange| range.dates().all(is_selectable);
+    // The chosen range, or the range that would be chosen while picking.
+    let preview = move || match anchor() {
+        Some(anchor) => Some(DateRange::new(anchor, hovered().unwrap_or_else(focused))),
+        None => state(),
+    };
+
+    let focus_day = {
+        let day_id = day_id.clone();
+        move |date: NaiveDate| {
+            let month = month_start(date);
+
+            if month < first_month() {
+                set_first_month(month);
+            } else if month > add_months(first_month(), 1) {
+                set_first_month(add_months(month, -1));
+            }
+
+            set_focused(date);
+            focus_by_id(day_id(date));
+        }
+    };
+    let open_popup = {
+        let focus_day = focus_day.clone();
+        move || {
+            if !disabled() {
+                let date = state()
+                    .map(|range| range.start)
+                    .unwrap_or_else(|| clamp_date(today(), min(), max()));
+                set_first_month(month_start(date));
+                set_anchor(None);
+                set_hovered(None);
+                set_open(true);
+                focus_day(date);
+            }
+        }
+    };
+    let close_popup = move |refocus: bool| {
+        set_open(false);
+        set_anchor(None);
+
+        if refocus {
+            if let Some(button) = button_ref.get_untracked() {
+                _ = button.focus();
+            }
+        }
+    };
+    // Picking a date that cannot be reached from the anchor without crossing
+    // a disabled date starts a new range from it instead.
+    let pick_date = move |date: NaiveDate| {
+        if is_selectable(date) {
+            match anchor().map(|anchor| DateRange::new(anchor, date)) {
+                Some(range) if is_range_selectable(range) => {
+                    set_state(Some(range));
+                    close_popup(true);
+                }
+                _ => set_anchor(Some(date)),
+            }
+        }
+    };
+
+    let on_grid_keydown = {
+        let focus_day = focus_day.clone();
+        move |ev: KeyboardEvent| {
+            let date = focused();
+            let target = match ev.key().as_str() {
+                "Enter" | " " => {
+                    ev.prevent_default();
+                    pick_date(date);
+                    None
+                }
+                "Escape" => {
+                    ev.prevent_default();
+
+                    if anchor().is_some() {
+                        set_anchor(None);
+                    } else {
+                        close_popup(true);
+                    }
+
+                    None
+                }
+                key => {
+                    calendar_key_target(key, ev.shift_key(), is_rtl(), date, first_day_of_week())
+                        .map(|target| clamp_date(target, min(), max()))
+                }
+            };
+
+            if let Some(target) = target {
+                ev.prevent_default();
+                set_hovered(None);
+                focus_day(target);
+            }
+        }
+    };
+
+    _ = on_click_outside(cx, container_ref, move |_| {
+        if open.get_untracked() {
+            close_popup(false);
+        }
+    });
+
+    // Whether navigating by a number of months would leave the allowed range.
+    let nav_disabled = move |months: i32| {
+        if months < 0 {
+            min().map_or(false, |min| {
+                month_end(add_months(first_month(), months)) < min
+            })
+        } else {
+            max().map_or(false, |max| add_months(first_month(), months + 1) > max)
+        }
+    };
+    let nav_button = move |months: i32, aria_label: &'static str, icon_class: &'static str| {
+        view! { cx,
+            <button
+                class="leptonic-date-picker-nav"
+                type="button"
+                aria-label=aria_label
+                disabled=move || nav_disabled(months)
+                on:click=move |_| {
+                    set_first_month(add_months(first_month(), months));
+                    set_focused(clamp_date(add_months(focused(), months), min(), max()));
+                }
+            >
+                <span class=icon_class></span>
+            </button>
+        }
+    };
+
+    let selected_text = move || {
+        state()
+            .map(|range| {
+                format!(
+                    "{} \u{2013} {}",
+                    range.start.format(&format()),
+                    range.end.format(&format())
+                )
+            })
+            .unwrap_or_else(|| placeholder.get())
+    };
+    let container_class = move || {
+        classes!(
+            "leptonic-date-picker-container",
+            disabled().then_some("leptonic-date-picker-container-disabled")
+        )
+    };
+    let button_class = {
+        let error = error.clone();
+        move || {
+            classes!(
+                "leptonic-date-picker-field",
+                "leptonic-date-range-picker-button",
+                open().then_some("leptonic-date-picker-field-open"),
+                state()
+                    .is_none()
+                    .then_some("leptonic-date-range-picker-button-placeholder"),
+                error().map(|_| "leptonic-date-picker-field-invalid")
+            )
+        }
+    };
+
+    let popup = {
+        let popup_id = popup_id.clone();
+        move || {
+            open().then(|| {
+                let day = {
+                    let day_id = day_id.clone();
+                    move |date: NaiveDate, month: NaiveDate| {
+                        if date.month() != month.month() {
+                            return view! { cx, <td role="gridcell"></td> };
+                        }
+
+                        let selectable = is_selectable(date);
+                        let in_range = move || preview().map_or(false, |range| range.contains(date));
+                        let is_endpoint = move || {
+                            preview().map_or(false, |range| range.start == date || range.end == date)
+                        };
+                        let day_class = move || {
+                            classes!(
+                                "leptonic-date-picker-day",
+                                (date == today()).then_some("leptonic-date-picker-day-today"),
+                                in_range().then_some("leptonic-date-range-picker-day-in-range"),
+                                is_endpoint().then_some("leptonic-date-picker-day-selected"),
+                                (!selectable).then_some("leptonic-date-picker-day-disabled")
+                            )
+                        };
+
+                        view! { cx,
+                            <td role="gridcell" aria-selected=move || in_range().to_string()>
+                                <button
+                                    id=day_id(date)
+                                    class=day_class
+                                    type="button"
+                                    tabindex=move || if focused() == date { "0" } else { "-1" }
+                                    aria-label=day_label(date)
+                                    aria-disabled=(!selectable).to_string()
+                                    on:click=move |_| pick_date(date)
+                                    on:focus=move |_| set_focused(date)
+                                    on:mouseenter=move |_| {
+                                        if selectable {
+                                            set_hovered(Some(date));
+                                        }
+                                    }
+                                >
+                                    {date.day()}
+                                </button>
+                            </td>
+                        }
+                    }
+                };
+                let month_view = |offset: i32| {
+                    let month = move || add_months(first_month(), offset);
+                    let header = if offset == 0 {
+                        view! { cx,
+                            {nav_button(-12, "Previous year", "leptonic-date-picker-prev-icon")}
+                            {nav_button(-1, "Previous month", "leptonic-date-picker-prev-icon")}
+                            <span class="leptonic-date-picker-header-label" aria-live="polite">
+                                {move || format!("{} {}", month_name(month()), month().year())}
+                            </span>
+                        }
+                    } else {
+                        view! { cx,
+                            <span class="leptonic-date-picker-header-label" aria-live="polite">
+                                {move || format!("{} {}", month_name(month()), month().year())}
+                            </span>
+                            {nav_button(1, "Next month", "leptonic-date-picker-next-icon")}
+                            {nav_button(12, "Next year", "leptonic-date-picker-next-icon")}
+                        }
+                    };
+
+                    view! { cx,
+                        <div class="leptonic-date-range-picker-month">
+                            <div class="leptonic-date-picker-header">{header}</div>
+                            {calendar_grid(cx, month, first_day_of_week, day.clone(), on_grid_keydown.clone())}
+                        </div>
+                    }
+                };
+                let preset_list = move || {
+                    let presets = presets();
+                    (!presets.is_empty()).then(|| {
+                        let items = presets
+                            .into_iter()
+                            .map(|preset| {
+                                let range = preset.range;
+                                let active = move || state() == Some(range);
+                                let preset_class = move || {
+                                    classes!(
+                                        "leptonic-date-range-picker-preset",
+                                        active().then_some("leptonic-date-range-picker-preset-active")
+                                    )
+                                };
+
+                                view! { cx,
+                                    <li>
+                                        <button
+                                            class=preset_class
+                                            type="button"
+                                            aria-pressed=move || active().to_string()
+                                            disabled=move || !is_range_selectable(range)
+                                            on:click=move |_| {
+                                                if is_range_selectable(range) {
+                                                    set_state(Some(range));
+                                                    close_popup(true);
+                                                }
+                                            }
+                                        >
+                                            {preset.label}
+                                        </button>
+                                    </li>
+                                }
+                            })
+                            .collect_view(cx);
+
+                        view! { cx,
+                            <ul class="leptonic-date-range-picker-presets" aria-label="Presets">
+                                {items}
+                            </ul>
+                        }
+                    })
+                };
+
+                view! { cx,
+                    <div
+                        id=popup_id.clone()
+                        class="leptonic-date-picker-popup leptonic-date-range-picker-popup"
+                        role="dialog"
+                        aria-modal="false"
+                        aria-label="Choose date range"
+                        on:mouseleave=move |_| set_hovered(None)
+                    >
+                        {preset_list}
+                        {month_view(0)}
+                        {month_view(1)}
+                    </div>
+                }
+            })
+        }
+    };
+
+    view! { cx,
+        <div class=container_class node_ref=container_ref>
+            <label for=id.clone() class="leptonic-date-picker-label">
+                {label}
+                <span class="leptonic-required-mark">{required().then_some(" *").unwrap_or_default()}</span>
+            </label>
+            <div class="leptonic-date-picker">
+                <button
+                    id=id
+                    class=button_class
+                    type="button"
+                    aria-haspopup="dialog"
+                    aria-controls=popup_id
+                    aria-expanded=move || open().to_string()
+                    aria-required=move || required().to_string()
+                    disabled=disabled
+                    node_ref=button_ref
+                    on:click=move |_| if open() { close_popup(false) } else { open_popup() }
+                >
+                    <span class="leptonic-date-range-picker-value">{selected_text}</span>
+                    <span class="leptonic-date-picker-icon"></span>
+                </button>
+                {popup}
+            </div>
+            <Error message=error size=ErrorSize::Small />
+        </div>
+    }
+}
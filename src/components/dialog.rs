@@ -0,0 +1,321 @@
+use super::button::*;
+use super::input::*;
+use super::modal::*;
+use leptos::*;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+/// The shared state of a dialog that has not yet been answered.
+struct DialogState<T> {
+    /// The dialog's answer, once given.
+    answer: Option<T>,
+    /// The waker of the task awaiting the answer.
+    waker: Option<Waker>,
+}
+
+/// A future that resolves to the answer given in a dialog.
+struct DialogAnswer<T>(Rc<RefCell<DialogState<T>>>);
+
+impl<T> Future for DialogAnswer<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.0.borrow_mut();
+
+        match state.answer.take() {
+            Some(answer) => Poll::Ready(answer),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Mounts a modal dialog in its own scope, resolving to the first answer
+/// given. The dialog is closed and unmounted once it has been answered.
+/// `dialog` is given the dialog scope, the modal's open state, and a function
+/// to answer the dialog with.
+fn show_dialog<T, F>(cx: Scope, dialog: F) -> impl Future<Output = T>
+where
+    T: 'static,
+    F: FnOnce(Scope, ReadSignal<bool>, WriteSignal<bool>, Rc<dyn Fn(T)>) + 'static,
+{
+    let state = Rc::new(RefCell::new(DialogState {
+        answer: None,
+        waker: None,
+    }));
+    let disposer = Rc::new(RefCell::new(None::<ScopeDisposer>));
+
+    let answer = {
+        let state = Rc::clone(&state);
+        let disposer = Rc::clone(&disposer);
+        move |set_open: WriteSignal<bool>| {
+            let state = Rc::clone(&state);
+            let disposer = Rc::clone(&disposer);
+            Rc::new(move |answer: T| {
+                let waker = {
+                    let mut state = state.borrow_mut();
+
+                    if state.answer.is_some() || disposer.borrow().is_none() {
+                        return;
+                    }
+
+                    state.answer = Some(answer);
+                    state.waker.take()
+                };
+
+                set_open(false);
+
+                // Unmount the dialog once the current event has been handled.
+                let disposer = Rc::clone(&disposer);
+                set_timeout(
+                    move || {
+                        if let Some(disposer) = disposer.borrow_mut().take() {
+                            disposer.dispose();
+                        }
+                    },
+                    Duration::ZERO,
+                );
+
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            }) as Rc<dyn Fn(T)>
+        }
+    };
+
+    let scope_disposer = cx.child_scope(move |cx| {
+        let (open, set_open) = create_signal(cx, true);
+        dialog(cx, open, set_open, answer(set_open));
+    });
+    *disposer.borrow_mut() = Some(scope_disposer);
+
+    DialogAnswer(state)
+}
+
+/// A confirmation dialog with a message, and confirm and cancel buttons.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfirmDialog {
+    /// The dialog title.
+    pub title: String,
+    /// The dialog message.
+    pub message: String,
+    /// The text on the confirm button.
+    pub confirm_text: String,
+    /// The text on the cancel button.
+    pub cancel_text: String,
+    /// The style of the confirm button.
+    pub confirm_style: ButtonStyle,
+}
+
+impl ConfirmDialog {
+    /// Creates a new confirmation dialog.
+    pub fn new(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            confirm_text: "OK".to_owned(),
+            cancel_text: "Cancel".to_owned(),
+            confirm_style: ButtonStyle::Primary,
+        }
+    }
+
+    /// Sets the text on the confirm button.
+    pub fn confirm_text(mut self, confirm_text: impl Into<String>) -> Self {
+        self.confirm_text = confirm_text.into();
+        self
+    }
+
+    /// Sets the text on the cancel button.
+    pub fn cancel_text(mut self, cancel_text: impl Into<String>) -> Self {
+        self.cancel_text = cancel_text.into();
+        self
+    }
+
+    /// Sets the style of the confirm button.
+    pub fn confirm_style(mut self, confirm_style: ButtonStyle) -> Self {
+        self.confirm_style = confirm_style;
+        self
+    }
+
+    /// Marks the confirmed action as destructive, styling the confirm button
+    /// as [`ButtonStyle::Danger`].
+    pub fn danger(self) -> Self {
+        self.confirm_style(ButtonStyle::Danger)
+    }
+
+    /// Shows the dialog, resolving to whether it was confirmed. Dismissing
+    /// the dialog counts as cancelling it.
+    pub fn show(self, cx: Scope) -> impl Future<Output = bool> {
+        show_dialog(cx, move |cx, open, set_open, answer| {
+            let actions = vec![
+                ModalAction::new(self.cancel_text)
+                    .style(ButtonStyle::Secondary)
+                    .on_click({
+                        let answer = Rc::clone(&answer);
+                        move || answer(false)
+                    }),
+                ModalAction::new(self.confirm_text)
+                    .style(self.confirm_style)
+                    .on_click({
+                        let answer = Rc::clone(&answer);
+                        move || answer(true)
+                    }),
+            ];
+            let message = self.message;
+
+            _ = view! { cx,
+                <Modal
+                    state=open
+                    set_state=set_open
+                    title=self.title
+                    size=ModalSize::Small
+                    actions
+                    on_dismiss=move |_| {
+                        answer(false);
+                        true
+                    }
+                >
+                    <p class="leptonic-dialog-message">{message.clone()}</p>
+                </Modal>
+            };
+        })
+    }
+}
+
+/// A dialog asking for a line of text, with submit and cancel buttons.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptDialog {
+    /// The dialog title.
+    pub title: String,
+    /// The dialog message.
+    pub message: String,
+    /// The initial value of the text input.
+    pub default_value: String,
+    /// The text input placeholder.
+    pub placeholder: String,
+    /// The text on the submit button.
+    pub submit_text: String,
+    /// The text on the cancel button.
+    pub cancel_text: String,
+}
+
+impl PromptDialog {
+    /// Creates a new prompt dialog.
+    pub fn new(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            default_value: String::new(),
+            placeholder: String::new(),
+            submit_text: "OK".to_owned(),
+            cancel_text: "Cancel".to_owned(),
+        }
+    }
+
+    /// Sets the initial value of the text input.
+    pub fn default_value(mut self, default_value: impl Into<String>) -> Self {
+        self.default_value = default_value.into();
+        self
+    }
+
+    /// Sets the text input placeholder.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the text on the submit button.
+    pub fn submit_text(mut self, submit_text: impl Into<String>) -> Self {
+        self.submit_text = submit_text.into();
+        self
+    }
+
+    /// Sets the text on the cancel button.
+    pub fn cancel_text(mut self, cancel_text: impl Into<String>) -> Self {
+        self.cancel_text = cancel_text.into();
+        self
+    }
+
+    /// Shows the dialog, resolving to the submitted text, or `None` if the
+    /// dialog was cancelled or dismissed.
+    pub fn show(self, cx: Scope) -> impl Future<Output = Option<String>> {
+        show_dialog(cx, move |cx, open, set_open, answer| {
+            let (value, set_value) = create_signal(cx, self.default_value);
+            let submit = {
+                let answer = Rc::clone(&answer);
+                move || answer(Some(value.get_untracked()))
+            };
+            let actions = vec![
+                ModalAction::new(self.cancel_text)
+                    .style(ButtonStyle::Secondary)
+                    .on_click({
+                        let answer = Rc::clone(&answer);
+                        move || answer(None)
+                    }),
+                ModalAction::new(self.submit_text).on_click(submit.clone()),
+            ];
+            let message = self.message;
+            let placeholder = self.placeholder;
+
+            _ = view! { cx,
+                <Modal
+                    state=open
+                    set_state=set_open
+                    title=self.title
+                    size=ModalSize::Small
+                    actions
+                    on_dismiss=move |_| {
+                        answer(None);
+                        true
+                    }
+                >
+                    <form
+                        class="leptonic-dialog-form"
+                        on:submit={
+                            let submit = submit.clone();
+                            move |ev| {
+                                ev.prevent_default();
+                                submit();
+                            }
+                        }
+                    >
+                        <Input
+                            state=value
+                            set_state=set_value
+                            label=message.clone()
+                            placeholder=placeholder.clone()
+                        />
+                    </form>
+                </Modal>
+            };
+        })
+    }
+}
+
+/// Shows a confirmation dialog, resolving to whether it was confirmed. This
+/// is a themed replacement for `window.confirm`. Use [`ConfirmDialog`] to
+/// customize the buttons, or to style the dialog as destructive.
+pub fn confirm(
+    cx: Scope,
+    title: impl Into<String>,
+    message: impl Into<String>,
+) -> impl Future<Output = bool> {
+    ConfirmDialog::new(title, message).show(cx)
+}
+
+/// Shows a dialog asking for a line of text, resolving to the submitted text,
+/// or `None` if the dialog was cancelled. This is a themed replacement for
+/// `window.prompt`. Use [`PromptDialog`] to customize the dialog.
+pub fn prompt(
+    cx: Scope,
+    title: impl Into<String>,
+    message: impl Into<String>,
+) -> impl Future<Output = Option<String>> {
+    PromptDialog::new(title, message).show(cx)
+}
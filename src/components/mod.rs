@@ -1,15 +1,53 @@
 mod button;
+mod button_group;
 mod checkbox;
+mod collapsible;
+mod date_picker;
+mod date_range_picker;
+mod dialog;
 mod error;
 mod input;
+mod modal;
+mod multi_select;
 mod number_input;
+mod pagination;
+mod popover;
+mod progress;
+mod radio_group;
+mod select;
+mod slider;
 mod switch;
+mod table;
+mod tabs;
 mod textarea;
+mod time_picker;
+mod toast;
+mod tooltip;
+mod virtual_list;
 
 pub use button::*;
+pub use button_group::*;
 pub use checkbox::*;
+pub use collapsible::*;
+pub use date_picker::*;
+pub use date_range_picker::*;
+pub use dialog::*;
 pub use error::*;
 pub use input::*;
+pub use modal::*;
+pub use multi_select::*;
 pub use number_input::*;
+pub use pagination::*;
+pub use popover::*;
+pub use progress::*;
+pub use radio_group::*;
+pub use select::*;
+pub use slider::*;
 pub use switch::*;
+pub use table::*;
+pub use tabs::*;
 pub use textarea::*;
+pub use time_picker::*;
+pub use toast::*;
+pub use tooltip::*;
+pub use virtual_list::*;
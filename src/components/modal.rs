@@ -0,0 +1,368 @@
+use super::button::*;
+use crate::classes::*;
+use crate::util::*;
+use leptos::ev::KeyboardEvent;
+use leptos::html::Div;
+use leptos::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+thread_local! {
+    /// The IDs of all open modals, from bottom to top.
+    static MODAL_STACK: RefCell<Vec<String>> = RefCell::new(Vec::new());
+    /// The body's `overflow` style from before the first modal was opened.
+    static BODY_OVERFLOW: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// Adds a modal to the top of the stack of open modals, locking body scrolling
+/// if it is the first. Returns the modal's position in the stack.
+fn push_modal(id: &str) -> usize {
+    MODAL_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+
+        if stack.is_empty() {
+            if let Some(body) = document().body() {
+                let style = body.style();
+                BODY_OVERFLOW.with(|overflow| {
+                    *overflow.borrow_mut() = style.get_property_value("overflow").ok();
+                });
+                _ = style.set_property("overflow", "hidden");
+            }
+        }
+
+        stack.push(id.to_owned());
+        stack.len() - 1
+    })
+}
+
+/// Removes a modal from the stack of open modals, unlocking body scrolling if
+/// it was the last.
+fn remove_modal(id: &str) {
+    MODAL_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        stack.retain(|modal_id| modal_id != id);
+
+        if stack.is_empty() {
+            if let Some(body) = document().body() {
+                let style = body.style();
+                match BODY_OVERFLOW.with(|overflow| overflow.borrow_mut().take()) {
+                    Some(overflow) if !overflow.is_empty() => {
+                        _ = style.set_property("overflow", &overflow);
+                    }
+                    _ => {
+                        _ = style.remove_property("overflow");
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// The size of a modal.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ModalSize {
+    /// Small modal.
+    Small,
+    /// Medium modal.
+    #[default]
+    Medium,
+    /// Large modal.
+    Large,
+}
+
+impl ModalSize {
+    /// Gets the name of the modal size.
+    pub fn size_name(&self) -> &'static str {
+        match *self {
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+        }
+    }
+}
+
+/// The way in which a modal was dismissed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DismissReason {
+    /// The Escape key was pressed.
+    Escape,
+    /// The backdrop behind the modal was clicked.
+    Backdrop,
+    /// The modal's close button was clicked.
+    CloseButton,
+}
+
+/// An abstraction over a modal's dismissal callback. The callback returns
+/// whether the dismissal should go ahead.
+#[derive(Default)]
+pub struct OnDismissCallback(Option<Box<dyn Fn(DismissReason) -> bool + 'static>>);
+
+impl OnDismissCallback {
+    /// Call the callback, returning whether the dismissal is allowed.
+    fn call(&self, reason: DismissReason) -> bool {
+        match &self.0 {
+            Some(callback) => (**callback)(reason),
+            None => true,
+        }
+    }
+}
+
+impl<F> From<F> for OnDismissCallback
+where
+    F: Fn(DismissReason) -> bool + 'static,
+{
+    fn from(value: F) -> Self {
+        Self(Some(Box::new(value)))
+    }
+}
+
+impl<F> From<Option<F>> for OnDismissCallback
+where
+    F: Fn(DismissReason) -> bool + 'static,
+{
+    fn from(value: Option<F>) -> Self {
+        Self(match value {
+            Some(callback) => Some(Box::new(callback)),
+            None => None,
+        })
+    }
+}
+
+/// A button in a modal's footer.
+#[derive(Clone)]
+pub struct ModalAction {
+    /// The text on the button.
+    pub text: String,
+    /// The button style.
+    pub style: ButtonStyle,
+    /// The button click callback.
+    pub on_click: Rc<dyn Fn()>,
+}
+
+impl ModalAction {
+    /// Creates a new action that does nothing when clicked.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            style: ButtonStyle::default(),
+            on_click: Rc::new(|| {}),
+        }
+    }
+
+    /// Sets the button style.
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the button click callback.
+    pub fn on_click(mut self, on_click: impl Fn() + 'static) -> Self {
+        self.on_click = Rc::new(on_click);
+        self
+    }
+}
+
+/// A modal dialog, rendered above the rest of the page.
+#[component]
+pub fn Modal(
+    cx: Scope,
+    /// Whether the modal is open.
+    state: ReadSignal<bool>,
+    /// Modal open state setter.
+    set_state: WriteSignal<bool>,
+    /// The modal title.
+    #[prop(into, optional)]
+    title: MaybeSignal<String>,
+    /// The buttons in the modal footer.
+    #[prop(optional)]
+    actions: Vec<ModalAction>,
+    /// The modal size.
+    #[prop(into, optional)]
+    size: MaybeSignal<ModalSize>,
+    /// Whether pressing Escape dismisses the modal.
+    #[prop(into, optional, default = MaybeSignal::Static(true))]
+    close_on_escape: MaybeSignal<bool>,
+    /// Whether clicking the backdrop dismisses the modal.
+    #[prop(into, optional, default = MaybeSignal::Static(true))]
+    close_on_backdrop: MaybeSignal<bool>,
+    /// Called before the modal is dismissed. Returning `false` keeps it open.
+    #[prop(into, optional)]
+    on_dismiss: OnDismissCallback,
+    /// The modal body.
+    children: ChildrenFn,
+) -> impl IntoView {
+    let id = new_id();
+    let title_id = format!("{id}-title");
+
+    let actions = store_value(cx, actions);
+    let on_dismiss = store_value(cx, on_dismiss);
+    let (stack_index, set_stack_index) = create_signal(cx, 0usize);
+    let previous_focus = store_value(cx, None::<HtmlElement>);
+    let dialog_ref = create_node_ref::<Div>(cx);
+
+    let dismiss = move |reason: DismissReason| {
+        if on_dismiss.with_value(|callback| callback.call(reason)) {
+            set_state(false);
+        }
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| match ev.key().as_str() {
+        "Escape" if close_on_escape() => {
+            ev.prevent_default();
+            ev.stop_propagation();
+            dismiss(DismissReason::Escape);
+        }
+        "Tab" => {
+            let Some(dialog) = dialog_ref.get_untracked() else {
+                return;
+            };
+            let focusable = focusable_elements(&dialog);
+            let active = document()
+                .active_element()
+                .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+
+            let target = match (focusable.first(), focusable.last()) {
+                (Some(first), Some(last)) if ev.shift_key() && active.as_ref() == Some(first) => {
+                    Some(last)
+                }
+                (Some(first), Some(last)) if !ev.shift_key() && active.as_ref() == Some(last) => {
+                    Some(first)
+                }
+                (None, None) => None,
+                _ => return,
+            };
+
+            ev.prevent_default();
+
+            if let Some(target) = target {
+                _ = target.focus();
+            }
+        }
+        _ => {}
+    };
+
+    // Open and close the modal, moving focus in and out of it.
+    {
+        let id = id.clone();
+        create_effect(cx, move |was_open: Option<bool>| {
+            let open = state();
+
+            if open && was_open != Some(true) {
+                previous_focus.set_value(
+                    document()
+                        .active_element()
+                        .and_then(|element| element.dyn_into::<HtmlElement>().ok()),
+                );
+                set_stack_index(push_modal(&id));
+
+                request_animation_frame(move || {
+                    if let Some(dialog) = dialog_ref.get_untracked() {
+                        match focusable_elements(&dialog).first() {
+                            Some(element) => _ = element.focus(),
+                            None => _ = dialog.focus(),
+                        }
+                    }
+                });
+            } else if !open && was_open == Some(true) {
+                remove_modal(&id);
+
+                if let Some(element) = previous_focus.with_value(|element| element.clone()) {
+                    _ = element.focus();
+                }
+            }
+
+            open
+        });
+    }
+
+    {
+        let id = id.clone();
+        on_cleanup(cx, move || {
+            if state.get_untracked() {
+                remove_modal(&id);
+            }
+        });
+    }
+
+    let content = move || {
+        state().then(|| {
+            let footer = actions.with_value(|actions| {
+                (!actions.is_empty()).then(|| {
+                    let buttons = actions
+                        .iter()
+                        .cloned()
+                        .map(|action| {
+                            let on_click = action.on_click;
+                            view! { cx,
+                                <Button
+                                    text=action.text
+                                    style=action.style
+                                    on_click=move || on_click()
+                                />
+                            }
+                        })
+                        .collect_view(cx);
+
+                    view! { cx, <div class="leptonic-modal-footer">{buttons}</div> }
+                })
+            });
+            let modal_class = move || {
+                classes!(
+                    "leptonic-modal",
+                    format!("leptonic-modal-{}", size.with(|s| s.size_name()))
+                )
+            };
+
+            view! { cx,
+                <div
+                    class="leptonic-modal-backdrop"
+                    on:click=move |_| {
+                        if close_on_backdrop() {
+                            dismiss(DismissReason::Backdrop);
+                        }
+                    }
+                ></div>
+                <div
+                    class=modal_class
+                    role="dialog"
+                    aria-modal="true"
+                    aria-labelledby=title_id.clone()
+                    tabindex="-1"
+                    node_ref=dialog_ref
+                >
+                    <div class="leptonic-modal-header">
+                        <h2 id=title_id.clone() class="leptonic-modal-title">{title.clone()}</h2>
+                        <button
+                            class="leptonic-modal-close"
+                            type="button"
+                            aria-label="Close"
+                            on:click=move |_| dismiss(DismissReason::CloseButton)
+                        >
+                            <span class="leptonic-modal-close-icon"></span>
+                        </button>
+                    </div>
+                    <div class="leptonic-modal-body">{children(cx)}</div>
+                    {footer}
+                </div>
+            }
+        })
+    };
+
+    let layer = view! { cx,
+        <div
+            class="leptonic-modal-layer"
+            style=move || format!("--leptonic-modal-stack-index: {}", stack_index())
+            on:keydown=on_keydown
+        >
+            {content}
+        </div>
+    };
+
+    if let Some(body) = document().body() {
+        _ = body.append_child(&layer);
+    }
+
+    on_cleanup(cx, move || layer.remove());
+}
@@ -0,0 +1,491 @@
+use super::error::*;
+use super::select::*;
+use crate::classes::*;
+use crate::position::*;
+use crate::util::*;
+use leptos::ev::KeyboardEvent;
+use leptos::html::{Div, Input, Ul};
+use leptos::*;
+use leptos_use::on_click_outside;
+
+/// An entry in the multi-select popup list.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MultiSelectEntry {
+    /// The entry that selects or deselects all visible options.
+    All,
+    /// An option, by its index in the list of options.
+    Item(usize),
+}
+
+/// A select element allowing multiple options to be chosen, with selected
+/// options shown as removable chips.
+#[component]
+pub fn MultiSelect<T>(
+    cx: Scope,
+    /// Multi-select state.
+    state: ReadSignal<Vec<T>>,
+    /// Multi-select state setter.
+    set_state: WriteSignal<Vec<T>>,
+    /// The options to choose from.
+    #[prop(into)]
+    options: MaybeSignal<Vec<SelectOption<T>>>,
+    /// Multi-select label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// Text shown when no options are selected.
+    #[prop(into, optional)]
+    placeholder: MaybeSignal<String>,
+    /// The maximum number of options that can be selected.
+    #[prop(into, optional)]
+    max: MaybeSignal<Option<usize>>,
+    /// Whether to show an entry that selects all visible options.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    select_all: MaybeSignal<bool>,
+    /// Whether the multi-select is required.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    required: MaybeSignal<bool>,
+    /// Whether the multi-select is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+    /// An optional error message.
+    #[prop(into, optional)]
+    error: MaybeSignal<Option<String>>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let id = new_id();
+    let listbox_id = format!("{id}-listbox");
+    let entry_id = {
+        let listbox_id = listbox_id.clone();
+        move |entry: MultiSelectEntry| match entry {
+            MultiSelectEntry::All => format!("{listbox_id}-all"),
+            MultiSelectEntry::Item(index) => format!("{listbox_id}-{index}"),
+        }
+    };
+
+    let options = create_memo(cx, move |_| options());
+    let (open, set_open) = create_signal(cx, false);
+    let (query, set_query) = create_signal(cx, String::new());
+    let (highlighted, set_highlighted) = create_signal(cx, None::<usize>);
+    let container_ref = create_node_ref::<Div>(cx);
+    let input_ref = create_node_ref::<Input>(cx);
+    let anchor_ref = create_node_ref::<Div>(cx);
+    let popup_ref = create_node_ref::<Ul>(cx);
+
+    let is_selected = move |index: usize| {
+        options.with(|opts| {
+            state.with(|values| values.iter().any(|value| *value == opts[index].value))
+        })
+    };
+    let at_max = move || max().map_or(false, |max| state.with(|values| values.len()) >= max);
+
+    // The options matching the search query, by index.
+    let visible = create_memo(cx, move |_| {
+        let query = query.with(|query| query.trim().to_lowercase());
+        options.with(|opts| {
+            opts.iter()
+                .enumerate()
+                .filter(|(_, opt)| query.is_empty() || opt.label.to_lowercase().contains(&query))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>()
+        })
+    });
+    let show_select_all = move || select_all() && visible.with(|visible| !visible.is_empty());
+    let entries = move || {
+        show_select_all()
+            .then_some(MultiSelectEntry::All)
+            .into_iter()
+            .chain(visible.get().into_iter().map(MultiSelectEntry::Item))
+            .collect::<Vec<_>>()
+    };
+    let selectable = move || {
+        visible.with(|visible| {
+            visible
+                .iter()
+                .copied()
+                .filter(|&index| options.with(|opts| !opts[index].disabled))
+                .collect::<Vec<_>>()
+        })
+    };
+    let all_selected = move || {
+        let selectable = selectable();
+        !selectable.is_empty() && selectable.into_iter().all(is_selected)
+    };
+    let is_enabled = move |entry: MultiSelectEntry| match entry {
+        MultiSelectEntry::All => !selectable().is_empty() && (all_selected() || !at_max()),
+        MultiSelectEntry::Item(index) => {
+            options.with(|opts| !opts[index].disabled) && (is_selected(index) || !at_max())
+        }
+    };
+    let is_entry_enabled = move |position: usize| {
+        entries()
+            .get(position)
+            .map_or(false, |&entry| is_enabled(entry))
+    };
+    let entry_count = move || entries().len();
+
+    let toggle_option = move |index: usize| {
+        let value = options.with(|opts| opts[index].value.clone());
+
+        if is_selected(index) {
+            set_state.update(|values| values.retain(|v| *v != value));
+        } else if !at_max() {
+            set_state.update(|values| values.push(value));
+        }
+    };
+    let toggle_all = move || {
+        let selectable = selectable();
+
+        if all_selected() {
+            let removed = options.with(|opts| {
+                selectable
+                    .iter()
+                    .map(|&index| opts[index].value.clone())
+                    .collect::<Vec<_>>()
+            });
+            set_state.update(|values| values.retain(|v| !removed.contains(v)));
+        } else {
+            for index in selectable {
+                if !is_selected(index) && !at_max() {
+                    toggle_option(index);
+                }
+            }
+        }
+    };
+    let toggle_entry = move |entry: MultiSelectEntry| {
+        if is_enabled(entry) {
+            match entry {
+                MultiSelectEntry::All => toggle_all(),
+                MultiSelectEntry::Item(index) => toggle_option(index),
+            }
+        }
+    };
+    let remove_value = move |position: usize| {
+        set_state.update(|values| {
+            if position < values.len() {
+                values.remove(position);
+            }
+        });
+    };
+
+    let open_popup = move || {
+        if !disabled() && !open() {
+            set_highlighted(first_enabled_index(entry_count(), is_entry_enabled));
+            set_open(true);
+        }
+    };
+    let close_popup = move || {
+        set_open(false);
+        set_query(String::new());
+    };
+    let focus_input = move || {
+        if let Some(input) = input_ref.get_untracked() {
+            _ = input.focus();
+        }
+    };
+
+    let on_input = move |ev| {
+        set_query(input_event_value(&ev));
+        set_open(true);
+        set_highlighted(first_enabled_index(entry_count(), is_entry_enabled));
+    };
+    let on_keydown = move |ev: KeyboardEvent| match ev.key().as_str() {
+        "ArrowDown" | "ArrowUp" => {
+            ev.prevent_default();
+
+            if !open() {
+                open_popup();
+            } else {
+                let forward = ev.key() == "ArrowDown";
+                set_highlighted(step_enabled_index(
+                    entry_count(),
+                    highlighted(),
+                    forward,
+                    false,
+                    is_entry_enabled,
+                ));
+            }
+        }
+        "Enter" => {
+            ev.prevent_default();
+
+            if !open() {
+                open_popup();
+            } else if let Some(entry) = highlighted().and_then(|pos| entries().get(pos).copied()) {
+                toggle_entry(entry);
+            }
+        }
+        "Backspace" => {
+            if query.with(|query| query.is_empty()) {
+                let len = state.with(|values| values.len());
+
+                if len > 0 {
+                    ev.prevent_default();
+                    remove_value(len - 1);
+                }
+            }
+        }
+        "Escape" => {
+            if open() {
+                ev.prevent_default();
+                close_popup();
+            }
+        }
+        "Tab" => close_popup(),
+        _ => {}
+    };
+
+    _ = on_click_outside(cx, container_ref, move |_| close_popup());
+
+    use_floating(
+        cx,
+        move || {
+            // Adding or removing chips may resize the control.
+            state.track();
+            open()
+        },
+        move || node_ref_element(anchor_ref).map(Into::into),
+        move || node_ref_element(popup_ref),
+        || Placement::BOTTOM_START,
+        PositionOptions::default().offset(4.0),
+    );
+
+    {
+        let entry_id = entry_id.clone();
+        create_effect(cx, move |_| {
+            if open() {
+                if let Some(entry) = highlighted().and_then(|pos| entries().get(pos).copied()) {
+                    let id = entry_id(entry);
+                    request_animation_frame(move || scroll_into_view(&id));
+                }
+            }
+        });
+    }
+
+    // The selected values along with their labels, in selection order.
+    let chips = move || {
+        state.with(|values| {
+            options.with(|opts| {
+                values
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(position, value)| {
+                        opts.iter()
+                            .find(|opt| opt.value == *value)
+                            .map(|opt| (position, opt.label.clone()))
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+    let chip_views = move || {
+        chips()
+            .into_iter()
+            .map(|(position, chip_label)| {
+                let remove_label = format!("Remove {chip_label}");
+
+                view! { cx,
+                    <span class="leptonic-chip">
+                        <span class="leptonic-chip-label">{chip_label}</span>
+                        <button
+                            class="leptonic-chip-remove"
+                            type="button"
+                            tabindex="-1"
+                            aria-label=remove_label
+                            disabled=disabled
+                            on:mousedown=|ev| ev.prevent_default()
+                            on:click=move |ev| {
+                                ev.stop_propagation();
+                                remove_value(position);
+                            }
+                        >
+                            <span class="leptonic-chip-remove-icon"></span>
+                        </button>
+                    </span>
+                }
+            })
+            .collect_view(cx)
+    };
+
+    let container_class = move || {
+        classes!(
+            "leptonic-multi-select-container",
+            disabled().then_some("leptonic-multi-select-container-disabled")
+        )
+    };
+    let control_class = {
+        let error = error.clone();
+        move || {
+            classes!(
+                "leptonic-multi-select",
+                open().then_some("leptonic-multi-select-open"),
+                error().map(|_| "leptonic-multi-select-invalid")
+            )
+        }
+    };
+    let input_placeholder = move || {
+        state
+            .with(|values| values.is_empty())
+            .then(|| placeholder.get())
+            .unwrap_or_default()
+    };
+    let active_descendant = {
+        let entry_id = entry_id.clone();
+        move || {
+            open()
+                .then(|| highlighted().and_then(|pos| entries().get(pos).copied()))
+                .flatten()
+                .map(&entry_id)
+        }
+    };
+
+    let popup = {
+        let listbox_id = listbox_id.clone();
+        move || {
+            open().then(|| {
+                let entries = entries();
+                let entry_id = entry_id.clone();
+                let items = if entries.is_empty() {
+                    view! { cx,
+                        <li class="leptonic-select-empty" role="presentation">"No results"</li>
+                    }
+                    .into_view(cx)
+                } else {
+                    entries
+                        .iter()
+                        .enumerate()
+                        .map(|(position, &entry)| {
+                            let group_header = match entry {
+                                MultiSelectEntry::Item(index) => options.with(|opts| {
+                                    let previous = position.checked_sub(1).and_then(|pos| {
+                                        match entries[pos] {
+                                            MultiSelectEntry::Item(prev) => {
+                                                opts[prev].group.clone()
+                                            }
+                                            MultiSelectEntry::All => None,
+                                        }
+                                    });
+                                    opts[index]
+                                        .group
+                                        .clone()
+                                        .filter(|group| previous.as_ref() != Some(group))
+                                }),
+                                MultiSelectEntry::All => None,
+                            }
+                            .map(|group| {
+                                view! { cx,
+                                    <li class="leptonic-select-group" role="presentation">
+                                        {group}
+                                    </li>
+                                }
+                            });
+                            let (entry_label, grouped) = match entry {
+                                MultiSelectEntry::All => ("Select all".to_owned(), false),
+                                MultiSelectEntry::Item(index) => options.with(|opts| {
+                                    (opts[index].label.clone(), opts[index].group.is_some())
+                                }),
+                            };
+                            let entry_selected = move || match entry {
+                                MultiSelectEntry::All => all_selected(),
+                                MultiSelectEntry::Item(index) => is_selected(index),
+                            };
+                            let entry_class = move || {
+                                classes!(
+                                    "leptonic-select-option",
+                                    "leptonic-multi-select-option",
+                                    (highlighted() == Some(position))
+                                        .then_some("leptonic-select-option-highlighted"),
+                                    entry_selected()
+                                        .then_some("leptonic-multi-select-option-selected"),
+                                    (!is_enabled(entry))
+                                        .then_some("leptonic-select-option-disabled"),
+                                    grouped.then_some("leptonic-select-option-grouped"),
+                                    (entry == MultiSelectEntry::All)
+                                        .then_some("leptonic-multi-select-all")
+                                )
+                            };
+
+                            view! { cx,
+                                {group_header}
+                                <li
+                                    id=entry_id(entry)
+                                    class=entry_class
+                                    role="option"
+                                    aria-selected=move || entry_selected().to_string()
+                                    aria-disabled=move || (!is_enabled(entry)).to_string()
+                                    on:mousedown=|ev| ev.prevent_default()
+                                    on:mouseenter=move |_| {
+                                        if is_enabled(entry) {
+                                            set_highlighted(Some(position));
+                                        }
+                                    }
+                                    on:click=move |_| toggle_entry(entry)
+                                >
+                                    <span class="leptonic-multi-select-check"></span>
+                                    {entry_label}
+                                </li>
+                            }
+                        })
+                        .collect_view(cx)
+                };
+
+                view! { cx,
+                    <ul
+                        id=listbox_id.clone()
+                        class="leptonic-select-popup"
+                        role="listbox"
+                        aria-multiselectable="true"
+                        node_ref=popup_ref
+                    >
+                        {items}
+                    </ul>
+                }
+            })
+        }
+    };
+
+    view! { cx,
+        <div class=container_class node_ref=container_ref>
+            <label for=id.clone() class="leptonic-multi-select-label">
+                {label}
+                <span class="leptonic-required-mark">{required().then_some(" *").unwrap_or_default()}</span>
+            </label>
+            <div class="leptonic-select" node_ref=anchor_ref>
+                <div
+                    class=control_class
+                    on:click=move |_| {
+                        focus_input();
+                        open_popup();
+                    }
+                >
+                    <div class="leptonic-chip-list">
+                        {chip_views}
+                        <input
+                            id=id
+                            class="leptonic-multi-select-search"
+                            type="text"
+                            role="combobox"
+                            autocomplete="off"
+                            aria-autocomplete="list"
+                            aria-haspopup="listbox"
+                            aria-controls=listbox_id
+                            aria-expanded=move || open().to_string()
+                            aria-activedescendant=active_descendant
+                            aria-required=move || required().to_string()
+                            placeholder=input_placeholder
+                            prop:value=query
+                            disabled=disabled
+                            node_ref=input_ref
+                            on:input=on_input
+                            on:keydown=on_keydown
+                        />
+                    </div>
+                    <span class="leptonic-select-icon"></span>
+                </div>
+                {popup}
+            </div>
+            <Error message=error size=ErrorSize::Small />
+        </div>
+    }
+}
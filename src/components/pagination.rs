@@ -0,0 +1,196 @@
+use super::number_input::*;
+use super::select::*;
+use crate::classes::*;
+use crate::table::*;
+use leptos::ev::KeyboardEvent;
+use leptos::*;
+
+/// Links to the pages of a list of items, with buttons to move to the
+/// previous and next pages. When there are many pages, only those around the
+/// current page are linked. The compact variant shows the current page
+/// instead of the links, and is also used on narrow screens.
+#[component]
+pub fn Pagination(
+    cx: Scope,
+    /// The current page, starting at zero.
+    page: ReadSignal<usize>,
+    /// Current page setter.
+    set_page: WriteSignal<usize>,
+    /// The total number of items.
+    #[prop(into)]
+    total: MaybeSignal<usize>,
+    /// The number of items on each page.
+    #[prop(into, optional, default = MaybeSignal::Static(10))]
+    page_size: MaybeSignal<usize>,
+    /// Page size setter. If given, a selector for the page size is shown.
+    #[prop(optional)]
+    set_page_size: Option<WriteSignal<usize>>,
+    /// The page sizes to choose from.
+    #[prop(into, optional, default = MaybeSignal::Static(vec![10, 25, 50, 100]))]
+    page_sizes: MaybeSignal<Vec<usize>>,
+    /// The number of pages linked on either side of the current page.
+    #[prop(into, optional, default = MaybeSignal::Static(1))]
+    siblings: MaybeSignal<usize>,
+    /// Whether to show an input for jumping to a page.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    jump: MaybeSignal<bool>,
+    /// Whether to show the current page instead of links to pages.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    compact: MaybeSignal<bool>,
+    /// An accessible label for the pagination.
+    #[prop(into, optional, default = MaybeSignal::Static("Pagination".to_owned()))]
+    label: MaybeSignal<String>,
+    /// Whether the pagination is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    let page_count = create_memo(cx, move |_| page_count(total(), page_size()));
+    let current = create_memo(cx, move |_| page().min(page_count() - 1));
+    let go_to = move |target: usize| {
+        if target < page_count.get_untracked() && target != current.get_untracked() {
+            set_page(target);
+        }
+    };
+
+    let items = move || {
+        page_items(current(), page_count(), siblings())
+            .into_iter()
+            .map(|item| match item {
+                PageItem::Page(target) => {
+                    let selected = move || current() == target;
+                    let class = move || {
+                        classes!(
+                            "leptonic-pagination-button",
+                            "leptonic-pagination-page",
+                            selected().then_some("leptonic-pagination-page-selected")
+                        )
+                    };
+
+                    view! { cx,
+                        <li>
+                            <button
+                                class=class
+                                type="button"
+                                aria-label=format!("Page {}", target + 1)
+                                aria-current=move || selected().then_some("page")
+                                disabled=disabled
+                                on:click=move |_| go_to(target)
+                            >
+                                {target + 1}
+                            </button>
+                        </li>
+                    }
+                }
+                PageItem::Ellipsis => view! { cx,
+                    <li class="leptonic-pagination-ellipsis" aria-hidden="true">"…"</li>
+                },
+            })
+            .collect_view(cx)
+    };
+
+    let page_size_select = set_page_size.map(|set_page_size| {
+        let (size, set_size) = create_signal(cx, page_size.get_untracked());
+        let options = Signal::derive(cx, move || {
+            page_sizes()
+                .into_iter()
+                .map(|size| SelectOption::new(size, format!("{size} per page")))
+                .collect::<Vec<_>>()
+        });
+
+        create_effect(cx, move |_| set_size(page_size()));
+        // Stay on the page showing the first item of the current page.
+        create_effect(cx, move |_| {
+            let size = size();
+            let old_size = page_size.get_untracked();
+
+            if size != old_size {
+                let first_item = current.get_untracked() * old_size;
+                set_page_size(size);
+                set_page(first_item / size.max(1));
+            }
+        });
+
+        view! { cx,
+            <div class="leptonic-pagination-size">
+                <Select state=size set_state=set_size options=options disabled=disabled />
+            </div>
+        }
+    });
+
+    // The jump input only moves to its page when it is committed, so that
+    // typing a page number does not visit every page along the way.
+    let (target, set_target) = create_signal(cx, current.get_untracked() + 1);
+    create_effect(cx, move |_| set_target(current() + 1));
+    let commit_target = move || {
+        let target = target.get_untracked();
+
+        if (1..=page_count.get_untracked()).contains(&target) {
+            go_to(target - 1);
+        } else {
+            set_target(current.get_untracked() + 1);
+        }
+    };
+
+    let jump_input = move || {
+        jump().then(|| {
+            view! { cx,
+                <div
+                    class="leptonic-pagination-jump"
+                    on:keydown=move |ev: KeyboardEvent| {
+                        if ev.key() == "Enter" {
+                            ev.prevent_default();
+                            commit_target();
+                        }
+                    }
+                    on:focusout=move |_| commit_target()
+                >
+                    <NumberInput
+                        state=target
+                        set_state=set_target
+                        label="Go to page"
+                        min=1usize
+                        max=page_count
+                        decimals=0
+                        disabled=disabled
+                    />
+                </div>
+            }
+        })
+    };
+
+    let class = move || {
+        classes!(
+            "leptonic-pagination",
+            compact().then_some("leptonic-pagination-compact")
+        )
+    };
+
+    view! { cx,
+        <nav class=class aria-label=label>
+            <button
+                class="leptonic-pagination-button leptonic-pagination-previous"
+                type="button"
+                aria-label="Previous page"
+                disabled=move || disabled() || current() == 0
+                on:click=move |_| go_to(current.get_untracked().saturating_sub(1))
+            >
+                <span class="leptonic-pagination-icon leptonic-pagination-previous-icon"></span>
+            </button>
+            <ul class="leptonic-pagination-pages">{items}</ul>
+            <span class="leptonic-pagination-summary" aria-live="polite">
+                {move || format!("Page {} of {}", current() + 1, page_count())}
+            </span>
+            <button
+                class="leptonic-pagination-button leptonic-pagination-next"
+                type="button"
+                aria-label="Next page"
+                disabled={ move || disabled() || current() + 1 >= page_count() }
+                on:click=move |_| go_to(current.get_untracked() + 1)
+            >
+                <span class="leptonic-pagination-icon leptonic-pagination-next-icon"></span>
+            </button>
+            {page_size_select}
+            {jump_input}
+        </nav>
+    }
+}
@@ -0,0 +1,307 @@
+use crate::position::*;
+use crate::util::*;
+use leptos::ev::KeyboardEvent;
+use leptos::html::{Div, ElementDescriptor};
+use leptos::leptos_dom::helpers::TimeoutHandle;
+use leptos::*;
+use leptos_use::{on_click_outside, use_event_listener};
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use web_sys::Node;
+
+/// How long the pointer may leave a hover-triggered popup before it closes.
+const HOVER_HIDE_DELAY: Duration = Duration::from_millis(100);
+
+/// The interaction with an anchor element that opens a popup.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum FloatingTrigger {
+    /// Hovering over the anchor.
+    Hover,
+    /// Focusing the anchor.
+    Focus,
+    /// Hovering over or focusing the anchor.
+    HoverFocus,
+    /// Clicking the anchor.
+    #[default]
+    Click,
+}
+
+impl FloatingTrigger {
+    /// Gets the name of the trigger.
+    pub fn trigger_name(&self) -> &'static str {
+        match *self {
+            Self::Hover => "hover",
+            Self::Focus => "focus",
+            Self::HoverFocus => "hover-focus",
+            Self::Click => "click",
+        }
+    }
+
+    /// Whether hovering opens the popup.
+    fn on_hover(&self) -> bool {
+        matches!(*self, Self::Hover | Self::HoverFocus)
+    }
+
+    /// Whether focusing opens the popup.
+    fn on_focus(&self) -> bool {
+        matches!(*self, Self::Focus | Self::HoverFocus)
+    }
+
+    /// Whether clicking toggles the popup.
+    fn on_click(&self) -> bool {
+        matches!(*self, Self::Click)
+    }
+}
+
+/// Opens and closes a popup in response to interactions with its anchor and
+/// with the popup itself.
+pub(crate) fn use_floating_trigger<T>(
+    cx: Scope,
+    anchor: NodeRef<T>,
+    floating: NodeRef<Div>,
+    open: ReadSignal<bool>,
+    set_open: WriteSignal<bool>,
+    trigger: MaybeSignal<FloatingTrigger>,
+    show_delay: MaybeSignal<Duration>,
+) where
+    T: ElementDescriptor + Clone + 'static,
+{
+    let pending = store_value(cx, None::<TimeoutHandle>);
+    let cancel = move || {
+        if let Some(handle) = pending.get_value() {
+            handle.clear();
+        }
+        pending.set_value(None);
+    };
+    let set_open_after = move |value: bool, delay: Duration| {
+        cancel();
+
+        if delay.is_zero() {
+            set_open(value);
+        } else {
+            pending.set_value(set_timeout_with_handle(move || set_open(value), delay).ok());
+        }
+    };
+    let contains = move |node: &NodeRef<Div>, target: Option<web_sys::EventTarget>| {
+        let target = target.and_then(|target| target.dyn_into::<Node>().ok());
+        node.get_untracked()
+            .map_or(false, |element| element.contains(target.as_ref()))
+    };
+    let anchor_contains = move |target: Option<web_sys::EventTarget>| {
+        let target = target.and_then(|target| target.dyn_into::<Node>().ok());
+        node_ref_element(anchor).map_or(false, |element| element.contains(target.as_ref()))
+    };
+    let close_and_focus_anchor = move || {
+        cancel();
+        set_open(false);
+
+        if let Some(element) = node_ref_element(anchor) {
+            _ = element.focus();
+        }
+    };
+
+    on_cleanup(cx, cancel);
+
+    _ = use_event_listener(cx, anchor, ev::mouseenter, move |_| {
+        if trigger.get_untracked().on_hover() {
+            set_open_after(true, show_delay.get_untracked());
+        }
+    });
+    _ = use_event_listener(cx, anchor, ev::mouseleave, move |_| {
+        if trigger.get_untracked().on_hover() {
+            set_open_after(false, HOVER_HIDE_DELAY);
+        }
+    });
+    _ = use_event_listener(cx, anchor, ev::focusin, move |_| {
+        if trigger.get_untracked().on_focus() {
+            set_open_after(true, Duration::ZERO);
+        }
+    });
+    _ = use_event_listener(cx, anchor, ev::focusout, move |ev| {
+        if trigger.get_untracked().on_focus() && !contains(&floating, ev.related_target()) {
+            set_open_after(false, Duration::ZERO);
+        }
+    });
+    _ = use_event_listener(cx, anchor, ev::click, move |_| {
+        if trigger.get_untracked().on_click() {
+            set_open_after(!open.get_untracked(), Duration::ZERO);
+        }
+    });
+    _ = use_event_listener(cx, anchor, ev::keydown, move |ev| {
+        if ev.key() == "Escape" && open.get_untracked() {
+            ev.stop_propagation();
+            cancel();
+            set_open(false);
+        }
+    });
+
+    _ = use_event_listener(cx, floating, ev::mouseenter, move |_| {
+        if trigger.get_untracked().on_hover() {
+            cancel();
+        }
+    });
+    _ = use_event_listener(cx, floating, ev::mouseleave, move |_| {
+        if trigger.get_untracked().on_hover() {
+            set_open_after(false, HOVER_HIDE_DELAY);
+        }
+    });
+    _ = use_event_listener(cx, floating, ev::focusout, move |ev| {
+        let target = ev.related_target();
+        if trigger.get_untracked().on_focus()
+            && !contains(&floating, target.clone())
+            && !anchor_contains(target)
+        {
+            set_open_after(false, Duration::ZERO);
+        }
+    });
+    _ = use_event_listener(cx, floating, ev::keydown, move |ev: KeyboardEvent| {
+        if ev.key() == "Escape" {
+            ev.stop_propagation();
+            close_and_focus_anchor();
+        }
+    });
+
+    _ = on_click_outside(cx, floating, move |ev| {
+        if trigger.get_untracked().on_click() && !anchor_contains(ev.target()) {
+            set_open(false);
+        }
+    });
+}
+
+/// Renders a popup in a layer above the rest of the page, positioned next to
+/// its anchor while open. The layer is removed when the scope is disposed.
+pub(crate) fn floating_layer<T, V>(
+    cx: Scope,
+    anchor: NodeRef<T>,
+    floating: NodeRef<Div>,
+    open: ReadSignal<bool>,
+    placement: MaybeSignal<Placement>,
+    options: PositionOptions,
+    popup: impl Fn() -> V + 'static,
+) where
+    T: ElementDescriptor + Clone + 'static,
+    V: IntoView,
+{
+    use_floating(
+        cx,
+        open,
+        move || node_ref_element(anchor).map(Into::into),
+        move || node_ref_element(floating),
+        placement,
+        options,
+    );
+
+    let layer = view! { cx,
+        <div class="leptonic-floating-layer">
+            {move || open().then(&popup)}
+        </div>
+    };
+
+    if let Some(body) = document().body() {
+        _ = body.append_child(&layer);
+    }
+
+    on_cleanup(cx, move || layer.remove());
+}
+
+/// A popup of interactive content, anchored to another element.
+#[component]
+pub fn Popover<T>(
+    cx: Scope,
+    /// The element the popover is anchored to.
+    anchor: NodeRef<T>,
+    /// The popover title.
+    #[prop(into, optional)]
+    title: MaybeSignal<String>,
+    /// Where the popover is placed relative to its anchor.
+    #[prop(into, optional, default = MaybeSignal::Static(Placement::BOTTOM))]
+    placement: MaybeSignal<Placement>,
+    /// The interaction with the anchor that opens the popover.
+    #[prop(into, optional)]
+    trigger: MaybeSignal<FloatingTrigger>,
+    /// Whether to show an arrow pointing at the anchor.
+    #[prop(into, optional, default = MaybeSignal::Static(true))]
+    arrow: MaybeSignal<bool>,
+    /// The popover content.
+    children: ChildrenFn,
+) -> impl IntoView
+where
+    T: ElementDescriptor + Clone + 'static,
+{
+    let id = new_id();
+    let title_id = format!("{id}-title");
+    let (open, set_open) = create_signal(cx, false);
+    let floating = create_node_ref::<Div>(cx);
+
+    use_floating_trigger(
+        cx,
+        anchor,
+        floating,
+        open,
+        set_open,
+        trigger,
+        MaybeSignal::Static(Duration::ZERO),
+    );
+
+    {
+        let id = id.clone();
+        create_effect(cx, move |_| {
+            if let Some(element) = node_ref_element(anchor) {
+                _ = element.set_attribute("aria-haspopup", "dialog");
+                _ = element.set_attribute("aria-expanded", &open().to_string());
+                _ = element.set_attribute("aria-controls", &id);
+            }
+        });
+    }
+
+    // Move focus into the popover when it is opened by clicking.
+    create_effect(cx, move |_| {
+        if let (true, Some(element)) = (open() && trigger().on_click(), floating.get()) {
+            request_animation_frame(move || {
+                if let Some(first) = focusable_elements(&element).first() {
+                    _ = first.focus();
+                }
+            });
+        }
+    });
+
+    let popup = move || {
+        let title_id = title_id.clone();
+        let title = title.clone();
+        let has_title = title.with(|title| !title.is_empty());
+        let heading = {
+            let title_id = title_id.clone();
+            move || {
+                has_title.then(|| {
+                    view! { cx,
+                        <span id=title_id.clone() class="leptonic-popover-title">{title.clone()}</span>
+                    }
+                })
+            }
+        };
+
+        view! { cx,
+            <div
+                id=id.clone()
+                class="leptonic-popover"
+                role="dialog"
+                aria-labelledby=has_title.then_some(title_id)
+                node_ref=floating
+            >
+                {heading}
+                <div class="leptonic-popover-content">{children(cx)}</div>
+                {move || arrow().then(|| view! { cx, <div class="leptonic-floating-arrow"></div> })}
+            </div>
+        }
+    };
+
+    floating_layer(
+        cx,
+        anchor,
+        floating,
+        open,
+        placement,
+        PositionOptions::default(),
+        popup,
+    );
+}
@@ -0,0 +1,191 @@
+use crate::classes::*;
+use crate::util::*;
+use leptos::*;
+
+/// The color of a progress indicator.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ProgressColor {
+    /// The theme's primary color.
+    #[default]
+    Primary,
+    /// A color indicating success.
+    Success,
+    /// A color indicating a warning.
+    Warning,
+    /// A color indicating danger or failure.
+    Danger,
+}
+
+impl ProgressColor {
+    /// Gets the name of the color.
+    pub fn color_name(&self) -> &'static str {
+        match *self {
+            Self::Primary => "primary",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Danger => "danger",
+        }
+    }
+}
+
+/// A bar showing the progress of a task. Without a value, the bar is
+/// indeterminate, showing that the task is ongoing.
+#[component]
+pub fn ProgressBar(
+    cx: Scope,
+    /// The progress made, from 0 to `max`, or `None` if it is unknown.
+    #[prop(into, optional)]
+    value: MaybeSignal<Option<f64>>,
+    /// The value at which the task is complete.
+    #[prop(into, optional, default = MaybeSignal::Static(100.0))]
+    max: MaybeSignal<f64>,
+    /// The progress bar label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// The color of the progress bar.
+    #[prop(into, optional)]
+    color: MaybeSignal<ProgressColor>,
+) -> impl IntoView {
+    // `f64::clamp` would panic on a negative or NaN maximum.
+    let value = create_memo(cx, move |_| {
+        value().map(|value| value.max(0.0).min(max().max(0.0)))
+    });
+    let bar_class = move || {
+        classes!(
+            "leptonic-progress-bar",
+            format!("leptonic-progress-{}", color.with(|c| c.color_name())),
+            value()
+                .is_none()
+                .then_some("leptonic-progress-bar-indeterminate")
+        )
+    };
+    let fill_style = move || {
+        value()
+            .map(|value| {
+                let percent = if max() > 0.0 {
+                    value / max() * 100.0
+                } else {
+                    0.0
+                };
+                format!("width: {percent}%;")
+            })
+            .unwrap_or_default()
+    };
+    let label_id = format!("{}-label", new_id());
+
+    view! { cx,
+        <div class="leptonic-progress-bar-container">
+            <span id=label_id.clone() class="leptonic-progress-bar-label">{label}</span>
+            <div
+                class=bar_class
+                role="progressbar"
+                aria-labelledby=label_id
+                aria-valuemin="0"
+                aria-valuemax=move || max().to_string()
+                aria-valuenow=move || value().map(|value| value.to_string())
+            >
+                <div class="leptonic-progress-bar-fill" style=fill_style></div>
+            </div>
+        </div>
+    }
+}
+
+/// The size of a spinner.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SpinnerSize {
+    /// A small spinner.
+    Small,
+    /// A medium sized spinner.
+    #[default]
+    Medium,
+    /// A large spinner.
+    Large,
+}
+
+impl SpinnerSize {
+    /// Gets the name of the size.
+    pub fn size_name(&self) -> &'static str {
+        match *self {
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+        }
+    }
+}
+
+/// A circular spinner showing that a task is ongoing.
+#[component]
+pub fn Spinner(
+    cx: Scope,
+    /// The size of the spinner.
+    #[prop(into, optional)]
+    size: MaybeSignal<SpinnerSize>,
+    /// The color of the spinner.
+    #[prop(into, optional)]
+    color: MaybeSignal<ProgressColor>,
+    /// An accessible label for the spinner.
+    #[prop(into, optional, default = MaybeSignal::Static("Loading".to_owned()))]
+    label: MaybeSignal<String>,
+) -> impl IntoView {
+    let class = move || {
+        classes!(
+            "leptonic-spinner",
+            format!("leptonic-spinner-{}", size.with(|s| s.size_name())),
+            format!("leptonic-progress-{}", color.with(|c| c.color_name()))
+        )
+    };
+
+    view! { cx,
+        <svg class=class viewBox="0 0 50 50" role="progressbar" aria-label=label>
+            <circle class="leptonic-spinner-circle" cx="25" cy="25" r="20" fill="none" stroke-width="5"></circle>
+        </svg>
+    }
+}
+
+/// Covers its content with a spinner while loading. The content cannot be
+/// interacted with while it is covered.
+#[component]
+pub fn LoadingOverlay(
+    cx: Scope,
+    /// Whether the content is loading.
+    #[prop(into)]
+    loading: MaybeSignal<bool>,
+    /// Text shown below the spinner.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// The size of the spinner.
+    #[prop(into, optional)]
+    size: MaybeSignal<SpinnerSize>,
+    /// The content to cover.
+    children: Children,
+) -> impl IntoView {
+    let overlay = move || {
+        loading().then(|| {
+            let label = label.clone();
+            let has_label = label.with(|label| !label.is_empty());
+            let spinner_label = if has_label {
+                label.clone()
+            } else {
+                MaybeSignal::Static("Loading".to_owned())
+            };
+
+            view! { cx,
+                <div class="leptonic-loading-overlay">
+                    <Spinner size label=spinner_label />
+                    {has_label.then(|| view! { cx,
+                        <span class="leptonic-loading-overlay-label" aria-hidden="true">{label}</span>
+                    })}
+                </div>
+            }
+        })
+    };
+
+    view! { cx,
+        <div class="leptonic-loading-overlay-container" aria-busy=move || loading().to_string()>
+            <div class="leptonic-loading-overlay-content" inert=loading>
+                {children(cx)}
+            </div>
+            {overlay}
+        </div>
+    }
+}
@@ -0,0 +1,212 @@
+use super::error::*;
+use crate::classes::*;
+use crate::util::*;
+use leptos::ev::KeyboardEvent;
+use leptos::*;
+
+/// An option in a radio group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadioOption<T> {
+    /// The value of the option.
+    pub value: T,
+    /// The option label.
+    pub label: String,
+    /// A description shown below the label.
+    pub description: Option<String>,
+    /// Whether the option is disabled.
+    pub disabled: bool,
+}
+
+impl<T> RadioOption<T> {
+    /// Creates a new option.
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            description: None,
+            disabled: false,
+        }
+    }
+
+    /// Sets the description shown below the label.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets whether the option is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// The direction the options of a radio group are laid out in.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum RadioGroupLayout {
+    /// Options are stacked vertically.
+    #[default]
+    Vertical,
+    /// Options are placed side by side, wrapping when they do not fit.
+    Horizontal,
+}
+
+impl RadioGroupLayout {
+    /// Gets the name of the layout.
+    pub fn layout_name(&self) -> &'static str {
+        match *self {
+            Self::Vertical => "vertical",
+            Self::Horizontal => "horizontal",
+        }
+    }
+}
+
+/// A group of radio buttons, of which one can be selected.
+#[component]
+pub fn RadioGroup<T>(
+    cx: Scope,
+    /// Radio group state.
+    state: ReadSignal<T>,
+    /// Radio group state setter.
+    set_state: WriteSignal<T>,
+    /// The options to choose from.
+    #[prop(into)]
+    options: MaybeSignal<Vec<RadioOption<T>>>,
+    /// Radio group label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// The direction the options are laid out in.
+    #[prop(into, optional)]
+    layout: MaybeSignal<RadioGroupLayout>,
+    /// Whether the radio group is required.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    required: MaybeSignal<bool>,
+    /// Whether the radio group is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+    /// An optional error message.
+    #[prop(into, optional)]
+    error: MaybeSignal<Option<String>>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let id = new_id();
+    let label_id = format!("{id}-label");
+
+    let options = create_memo(cx, move |_| options());
+    let focus = RovingFocus::new(cx, id, state, set_state, move || {
+        options.with(|opts| {
+            opts.iter()
+                .map(|opt| (opt.value.clone(), !disabled.get() && !opt.disabled))
+                .collect()
+        })
+    });
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let target = match ev.key().as_str() {
+            "ArrowDown" | "ArrowUp" => focus.step(ev.key() == "ArrowDown"),
+            "ArrowLeft" | "ArrowRight" => focus.step((ev.key() == "ArrowRight") != is_rtl()),
+            " " => focus.tab_stop(),
+            _ => return,
+        };
+
+        ev.prevent_default();
+
+        if let Some(index) = target {
+            focus.select(index);
+        }
+    };
+
+    let container_class = move || {
+        classes!(
+            "leptonic-radio-group-container",
+            disabled().then_some("leptonic-radio-group-container-disabled")
+        )
+    };
+    let group_class = {
+        let error = error.clone();
+        move || {
+            classes!(
+                "leptonic-radio-group",
+                format!("leptonic-radio-group-{}", layout.with(|l| l.layout_name())),
+                error().map(|_| "leptonic-radio-group-invalid")
+            )
+        }
+    };
+    let invalid = {
+        let error = error.clone();
+        move || error().is_some().to_string()
+    };
+
+    let items = move || {
+        options.with(|opts| {
+            opts.iter()
+                .enumerate()
+                .map(|(index, opt)| {
+                    let checked = move || focus.selected_index() == Some(index);
+                    let option_disabled = move || !focus.is_enabled(index);
+                    let option_class = move || {
+                        classes!(
+                            "leptonic-radio",
+                            checked().then_some("leptonic-radio-checked"),
+                            option_disabled().then_some("leptonic-radio-disabled")
+                        )
+                    };
+                    let label_id = format!("{}-label", focus.option_id(index));
+                    let description_id = format!("{}-description", focus.option_id(index));
+                    let description = opt.description.clone().map(|description| {
+                        view! { cx,
+                            <span id=description_id.clone() class="leptonic-radio-description">
+                                {description}
+                            </span>
+                        }
+                    });
+
+                    view! { cx,
+                        <div
+                            id=focus.option_id(index)
+                            class=option_class
+                            role="radio"
+                            tabindex=move || focus.tabindex(index)
+                            aria-checked=move || checked().to_string()
+                            aria-disabled=move || option_disabled().to_string()
+                            aria-labelledby=label_id.clone()
+                            aria-describedby=opt.description.is_some().then_some(description_id)
+                            on:click=move |_| focus.select(index)
+                        >
+                            <span class="leptonic-radio-circle"></span>
+                            <span class="leptonic-radio-text">
+                                <span id=label_id class="leptonic-radio-label">
+                                    {opt.label.clone()}
+                                </span>
+                                {description}
+                            </span>
+                        </div>
+                    }
+                })
+                .collect_view(cx)
+        })
+    };
+
+    view! { cx,
+        <div class=container_class>
+            <span id=label_id.clone() class="leptonic-radio-group-label">
+                {label}
+                <span class="leptonic-required-mark">{required().then_some(" *").unwrap_or_default()}</span>
+            </span>
+            <div
+                class=group_class
+                role="radiogroup"
+                aria-labelledby=label_id
+                aria-required=move || required().to_string()
+                aria-disabled=move || disabled().to_string()
+                aria-invalid=invalid
+                on:keydown=on_keydown
+            >
+                {items}
+            </div>
+            <Error message=error size=ErrorSize::Small />
+        </div>
+    }
+}
@@ -0,0 +1,362 @@
+use super::error::*;
+use crate::classes::*;
+use crate::position::*;
+use crate::util::*;
+use leptos::ev::KeyboardEvent;
+use leptos::html::{Div, Ul};
+use leptos::*;
+use leptos_use::on_click_outside;
+
+/// An option in a select menu.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectOption<T> {
+    /// The value of the option.
+    pub value: T,
+    /// The option label.
+    pub label: String,
+    /// The group the option belongs to. Options in the same group should be
+    /// adjacent to one another.
+    pub group: Option<String>,
+    /// Whether the option is disabled.
+    pub disabled: bool,
+}
+
+impl<T> SelectOption<T> {
+    /// Creates a new option.
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            group: None,
+            disabled: false,
+        }
+    }
+
+    /// Sets the group the option belongs to.
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Sets whether the option is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// A select element.
+#[component]
+pub fn Select<T>(
+    cx: Scope,
+    /// Select state.
+    state: ReadSignal<T>,
+    /// Select state setter.
+    set_state: WriteSignal<T>,
+    /// The options to choose from.
+    #[prop(into)]
+    options: MaybeSignal<Vec<SelectOption<T>>>,
+    /// Select label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// Text shown when the state matches none of the options.
+    #[prop(into, optional)]
+    placeholder: MaybeSignal<String>,
+    /// Whether the select is required.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    required: MaybeSignal<bool>,
+    /// Whether the select is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+    /// An optional error message.
+    #[prop(into, optional)]
+    error: MaybeSignal<Option<String>>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let id = new_id();
+    let listbox_id = format!("{id}-listbox");
+    let option_id = {
+        let listbox_id = listbox_id.clone();
+        move |index: usize| format!("{listbox_id}-{index}")
+    };
+
+    let options = create_memo(cx, move |_| options());
+    let (open, set_open) = create_signal(cx, false);
+    let (highlighted, set_highlighted) = create_signal(cx, None::<usize>);
+    let typeahead = store_value(cx, Typeahead::default());
+    let container_ref = create_node_ref::<Div>(cx);
+    let anchor_ref = create_node_ref::<Div>(cx);
+    let popup_ref = create_node_ref::<Ul>(cx);
+
+    let is_enabled =
+        move |index: usize| options.with(|opts| opts.get(index).map_or(false, |opt| !opt.disabled));
+    let option_count = move || options.with(|opts| opts.len());
+    let selected_index = move || {
+        state.with(|value| options.with(|opts| opts.iter().position(|opt| opt.value == *value)))
+    };
+
+    let open_popup = move || {
+        if !disabled() {
+            let index = selected_index()
+                .filter(|&i| is_enabled(i))
+                .or_else(|| first_enabled_index(option_count(), is_enabled));
+            set_highlighted(index);
+            set_open(true);
+        }
+    };
+    let select_index = move |index: usize| {
+        if is_enabled(index) {
+            if let Some(value) = options.with(|opts| opts.get(index).map(|opt| opt.value.clone())) {
+                set_state(value);
+            }
+            set_open(false);
+        }
+    };
+    let search = move |chr: char| {
+        let now = js_sys::Date::now();
+        let current = if open() {
+            highlighted()
+        } else {
+            selected_index()
+        };
+        typeahead.update_value(|t| {
+            let found = t.search(chr, now, option_count(), current, |index| {
+                options.with(|opts| {
+                    opts.get(index)
+                        .filter(|opt| !opt.disabled)
+                        .map(|opt| opt.label.clone())
+                })
+            });
+
+            if let Some(index) = found {
+                if open() {
+                    set_highlighted(Some(index));
+                } else {
+                    select_index(index);
+                }
+            }
+        });
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let key = ev.key();
+        let typing = typeahead.with_value(|t| t.is_active(js_sys::Date::now()));
+
+        if !open() {
+            match key.as_str() {
+                "ArrowDown" | "ArrowUp" | "Enter" => {
+                    ev.prevent_default();
+                    open_popup();
+                }
+                " " if !typing => {
+                    ev.prevent_default();
+                    open_popup();
+                }
+                _ => {}
+            }
+        } else {
+            match key.as_str() {
+                "ArrowDown" | "ArrowUp" => {
+                    ev.prevent_default();
+                    let forward = key == "ArrowDown";
+                    set_highlighted(step_enabled_index(
+                        option_count(),
+                        highlighted(),
+                        forward,
+                        false,
+                        is_enabled,
+                    ));
+                }
+                "Home" => {
+                    ev.prevent_default();
+                    set_highlighted(first_enabled_index(option_count(), is_enabled));
+                }
+                "End" => {
+                    ev.prevent_default();
+                    set_highlighted(last_enabled_index(option_count(), is_enabled));
+                }
+                "Enter" => {
+                    ev.prevent_default();
+                    if let Some(index) = highlighted() {
+                        select_index(index);
+                    }
+                }
+                " " if !typing => {
+                    ev.prevent_default();
+                    if let Some(index) = highlighted() {
+                        select_index(index);
+                    }
+                }
+                "Escape" => {
+                    ev.prevent_default();
+                    set_open(false);
+                }
+                "Tab" => set_open(false),
+                _ => {}
+            }
+        }
+
+        let mut chars = key.chars();
+        if let (Some(chr), None) = (chars.next(), chars.next()) {
+            if !ev.ctrl_key() && !ev.meta_key() && !ev.alt_key() && (chr != ' ' || typing) {
+                search(chr);
+            }
+        }
+    };
+
+    _ = on_click_outside(cx, container_ref, move |_| set_open(false));
+
+    use_floating(
+        cx,
+        open,
+        move || node_ref_element(anchor_ref).map(Into::into),
+        move || node_ref_element(popup_ref),
+        || Placement::BOTTOM_START,
+        PositionOptions::default().offset(4.0),
+    );
+
+    {
+        let option_id = option_id.clone();
+        create_effect(cx, move |_| {
+            if open() {
+                if let Some(index) = highlighted() {
+                    let id = option_id(index);
+                    request_animation_frame(move || scroll_into_view(&id));
+                }
+            }
+        });
+    }
+
+    let selected_label = move || {
+        selected_index()
+            .map(|index| options.with(|opts| opts[index].label.clone()))
+            .unwrap_or_else(|| placeholder.get())
+    };
+    let container_class = move || {
+        classes!(
+            "leptonic-select-container",
+            disabled().then_some("leptonic-select-container-disabled")
+        )
+    };
+    let button_class = {
+        let error = error.clone();
+        move || {
+            classes!(
+                "leptonic-select-button",
+                open().then_some("leptonic-select-button-open"),
+                selected_index()
+                    .is_none()
+                    .then_some("leptonic-select-button-placeholder"),
+                error().map(|_| "leptonic-select-button-invalid")
+            )
+        }
+    };
+    let active_descendant = {
+        let option_id = option_id.clone();
+        move || open().then(|| highlighted().map(&option_id)).flatten()
+    };
+
+    let popup = {
+        let listbox_id = listbox_id.clone();
+        move || {
+            open().then(|| {
+                let items = options.with(|opts| {
+                    opts.iter()
+                        .enumerate()
+                        .map(|(index, opt)| {
+                            let group_header = opt
+                                .group
+                                .as_ref()
+                                .filter(|group| {
+                                    index == 0 || opts[index - 1].group.as_ref() != Some(*group)
+                                })
+                                .map(|group| {
+                                    view! { cx,
+                                        <li class="leptonic-select-group" role="presentation">
+                                            {group.clone()}
+                                        </li>
+                                    }
+                                });
+                            let option_disabled = opt.disabled;
+                            let grouped = opt.group.is_some();
+                            let option_class = move || {
+                                classes!(
+                                    "leptonic-select-option",
+                                    (highlighted() == Some(index))
+                                        .then_some("leptonic-select-option-highlighted"),
+                                    (selected_index() == Some(index))
+                                        .then_some("leptonic-select-option-selected"),
+                                    option_disabled.then_some("leptonic-select-option-disabled"),
+                                    grouped.then_some("leptonic-select-option-grouped")
+                                )
+                            };
+
+                            view! { cx,
+                                {group_header}
+                                <li
+                                    id=option_id(index)
+                                    class=option_class
+                                    role="option"
+                                    aria-selected=move || (selected_index() == Some(index)).to_string()
+                                    aria-disabled=option_disabled.to_string()
+                                    on:mousedown=|ev| ev.prevent_default()
+                                    on:mouseenter=move |_| {
+                                        if !option_disabled {
+                                            set_highlighted(Some(index));
+                                        }
+                                    }
+                                    on:click=move |_| select_index(index)
+                                >
+                                    {opt.label.clone()}
+                                </li>
+                            }
+                        })
+                        .collect_view(cx)
+                });
+
+                view! { cx,
+                    <ul
+                        id=listbox_id.clone()
+                        class="leptonic-select-popup"
+                        role="listbox"
+                        node_ref=popup_ref
+                    >
+                        {items}
+                    </ul>
+                }
+            })
+        }
+    };
+
+    view! { cx,
+        <div class=container_class node_ref=container_ref>
+            <label for=id.clone() class="leptonic-select-label">
+                {label}
+                <span class="leptonic-required-mark">{required().then_some(" *").unwrap_or_default()}</span>
+            </label>
+            <div class="leptonic-select" node_ref=anchor_ref>
+                <button
+                    id=id
+                    class=button_class
+                    type="button"
+                    role="combobox"
+                    aria-haspopup="listbox"
+                    aria-controls=listbox_id
+                    aria-expanded=move || open().to_string()
+                    aria-activedescendant=active_descendant
+                    aria-required=move || required().to_string()
+                    disabled=disabled
+                    on:click=move |_| if open() { set_open(false) } else { open_popup() }
+                    on:keydown=on_keydown
+                >
+                    <span class="leptonic-select-value">{selected_label}</span>
+                    <span class="leptonic-select-icon"></span>
+                </button>
+                {popup}
+            </div>
+            <Error message=error size=ErrorSize::Small />
+        </div>
+    }
+}
@@ -0,0 +1,544 @@
+use super::number_input::*;
+use crate::classes::*;
+use crate::number::*;
+use crate::util::*;
+use leptos::ev::{KeyboardEvent, PointerEvent};
+use leptos::html::Div;
+use leptos::*;
+
+/// The number of steps moved by the page up and page down keys.
+const PAGE_STEPS: f64 = 10.0;
+
+/// The direction a slider is laid out in.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SliderOrientation {
+    /// The slider runs from the inline start to the inline end.
+    #[default]
+    Horizontal,
+    /// The slider runs from bottom to top.
+    Vertical,
+}
+
+impl SliderOrientation {
+    /// Gets the name of the orientation.
+    pub fn orientation_name(&self) -> &'static str {
+        match *self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+}
+
+/// A tick mark along a slider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliderMark<N> {
+    /// The value the mark is placed at.
+    pub value: N,
+    /// A label shown next to the mark.
+    pub label: Option<String>,
+}
+
+impl<N> SliderMark<N> {
+    /// Creates a new mark.
+    pub fn new(value: N) -> Self {
+        Self { value, label: None }
+    }
+
+    /// Sets the label shown next to the mark.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// Gets how far along the range from `min` to `max` a value is, from 0 to 1.
+///
+/// ```
+/// # use leptonic::value_fraction;
+/// assert_eq!(value_fraction(25, 0, 100), 0.25);
+/// assert_eq!(value_fraction(150, 0, 100), 1.0);
+/// assert_eq!(value_fraction(5, 5, 5), 0.0);
+/// ```
+pub fn value_fraction<N: Number>(value: N, min: N, max: N) -> f64 {
+    let range = max.as_f64() - min.as_f64();
+
+    if range.is_finite() && range > 0.0 {
+        ((value.as_f64() - min.as_f64()) / range).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Gets the number of decimal places in a number.
+fn decimal_places<N: Number>(number: N) -> i32 {
+    number
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len()) as i32
+}
+
+/// Rounds a value to the nearest step counted from `min`, keeping it between
+/// `min` and `max`. A step of zero or less leaves the value unrounded.
+///
+/// ```
+/// # use leptonic::snap_to_step;
+/// assert_eq!(snap_to_step(7.4, 0, 10, 5), 5);
+/// assert_eq!(snap_to_step(7.6, 0, 10, 5), 10);
+/// assert_eq!(snap_to_step(-3.0, 0, 10, 5), 0);
+/// assert_eq!(snap_to_step(0.31, 0.0, 1.0, 0.1), 0.3);
+///
+/// // Steps are counted from `min`, even when it is not a multiple of `step`.
+/// assert_eq!(snap_to_step(7.0, 0.5, 10.0, 1.0), 7.5);
+/// assert_eq!(snap_to_step(0.15, 0.05, 1.0, 0.1), 0.15);
+/// ```
+pub fn snap_to_step<N: Number>(value: f64, min: N, max: N, step: N) -> N {
+    let (min_f, max_f, step_f) = (min.as_f64(), max.as_f64(), step.as_f64());
+
+    if !value.is_finite() || value <= min_f {
+        return min;
+    }
+
+    if value >= max_f {
+        return max;
+    }
+
+    if step_f <= 0.0 {
+        return N::from_f64(value);
+    }
+
+    let snapped = min_f + ((value - min_f) / step_f).round() * step_f;
+
+    // Remove the rounding error of repeatedly adding decimal steps. Values on
+    // the grid have no more decimals than `min` and `step`.
+    let decimals = decimal_places(min).max(decimal_places(step));
+    let scale = 10f64.powi(decimals);
+    let snapped = ((snapped * scale).round() / scale).min(max_f);
+
+    N::from_f64(snapped)
+}
+
+/// Renders the track, marks, and thumbs of a slider with one or more thumbs.
+/// `value` gets the value of a thumb, and `set_value` moves a thumb.
+#[allow(clippy::too_many_arguments)]
+fn slider_track<N>(
+    cx: Scope,
+    thumbs: usize,
+    value: impl Fn(usize) -> N + Copy + 'static,
+    set_value: impl Fn(usize, N) + Copy + 'static,
+    min: MaybeSignal<N>,
+    max: MaybeSignal<N>,
+    step: MaybeSignal<N>,
+    label_id: String,
+    orientation: MaybeSignal<SliderOrientation>,
+    marks: MaybeSignal<Vec<SliderMark<N>>>,
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    N: Number + 'static,
+{
+    let id = new_id();
+    let thumb_id = move |index: usize| format!("{id}-{index}");
+    let area_ref = create_node_ref::<Div>(cx);
+    let (dragging, set_dragging) = create_signal(cx, None::<usize>);
+
+    let vertical = move || orientation() == SliderOrientation::Vertical;
+    let fraction = move |value: N| value_fraction(value, min(), max());
+
+    let pointer_value = move |ev: &PointerEvent| {
+        let area = area_ref.get_untracked()?;
+        let rect = element_rect(&area);
+        let (x, y) = (f64::from(ev.client_x()), f64::from(ev.client_y()));
+        let fraction = if vertical() {
+            (rect.bottom() - y) / rect.height
+        } else if is_rtl() {
+            (rect.right() - x) / rect.width
+        } else {
+            (x - rect.x) / rect.width
+        };
+        let (low, high) = (min().as_f64(), max().as_f64());
+
+        Some(snap_to_step(
+            low + fraction.clamp(0.0, 1.0) * (high - low),
+            min(),
+            max(),
+            step(),
+        ))
+    };
+
+    let on_pointerdown = {
+        let thumb_id = thumb_id.clone();
+        move |ev: PointerEvent| {
+            if disabled() || ev.button() != 0 {
+                return;
+            }
+
+            let Some(new_value) = pointer_value(&ev) else {
+                return;
+            };
+
+            // Move the thumb nearest to the pointer, preferring the thumb
+            // on the side the pointer is on when several are stacked.
+            let distance = |index: usize| (value(index).as_f64() - new_value.as_f64()).abs();
+            let index = (0..thumbs)
+                .min_by(|&a, &b| {
+                    distance(a)
+                        .partial_cmp(&distance(b))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                        .then_with(|| {
+                            if new_value > value(a) {
+                                b.cmp(&a)
+                            } else {
+                                a.cmp(&b)
+                            }
+                        })
+                })
+                .unwrap_or(0);
+
+            ev.prevent_default();
+            set_value(index, new_value);
+            set_dragging(Some(index));
+            focus_by_id(thumb_id(index));
+
+            if let Some(area) = area_ref.get_untracked() {
+                _ = area.set_pointer_capture(ev.pointer_id());
+            }
+        }
+    };
+    let on_pointermove = move |ev: PointerEvent| {
+        if let Some(index) = dragging.get_untracked() {
+            if let Some(new_value) = pointer_value(&ev) {
+                set_value(index, new_value);
+            }
+        }
+    };
+    let stop_dragging = move |_| set_dragging(None);
+
+    let on_keydown = move |index: usize, ev: KeyboardEvent| {
+        if disabled() {
+            return;
+        }
+
+        let forward = if !vertical() && is_rtl() { -1.0 } else { 1.0 };
+        let new_value = match ev.key().as_str() {
+            "Home" => min(),
+            "End" => max(),
+            key => {
+                let steps = match key {
+                    "ArrowRight" => forward,
+                    "ArrowLeft" => -forward,
+                    "ArrowUp" => 1.0,
+                    "ArrowDown" => -1.0,
+                    "PageUp" => PAGE_STEPS,
+                    "PageDown" => -PAGE_STEPS,
+                    _ => return,
+                };
+
+                snap_to_step(
+                    value(index).as_f64() + steps * step().as_f64(),
+                    min(),
+                    max(),
+                    step(),
+                )
+            }
+        };
+
+        ev.prevent_default();
+        set_value(index, new_value);
+    };
+
+    let fill_style = move || {
+        let (start, end) = if thumbs > 1 {
+            (fraction(value(0)), fraction(value(thumbs - 1)))
+        } else {
+            (0.0, fraction(value(0)))
+        };
+
+        format!("--leptonic-slider-fill-start: {start}; --leptonic-slider-fill-end: {end};")
+    };
+
+    let mark_views = {
+        let marks = marks.clone();
+        move || {
+            marks.with(|marks| {
+                marks
+                    .iter()
+                    .map(|mark| {
+                        let position = fraction(mark.value);
+                        let label = mark.label.clone().map(|label| {
+                            view! { cx, <span class="leptonic-slider-mark-label">{label}</span> }
+                        });
+
+                        view! { cx,
+                            <div
+                                class="leptonic-slider-mark"
+                                style=format!("--leptonic-slider-position: {position};")
+                            >
+                                {label}
+                            </div>
+                        }
+                    })
+                    .collect_view(cx)
+            })
+        }
+    };
+
+    let thumb_views = (0..thumbs)
+        .map(|index| {
+            let thumb_class = move || {
+                classes!(
+                    "leptonic-slider-thumb",
+                    (dragging() == Some(index)).then_some("leptonic-slider-thumb-active")
+                )
+            };
+            let aria_label = match (thumbs, index) {
+                (1, _) => None,
+                (_, 0) => Some("Minimum"),
+                _ => Some("Maximum"),
+            };
+
+            view! { cx,
+                <div
+                    id=thumb_id(index)
+                    class=thumb_class
+                    style=move || format!("--leptonic-slider-position: {};", fraction(value(index)))
+                    role="slider"
+                    tabindex=move || if disabled() { "-1" } else { "0" }
+                    aria-labelledby=aria_label.is_none().then(|| label_id.clone())
+                    aria-label=aria_label
+                    aria-valuemin=move || min().to_string()
+                    aria-valuemax=move || max().to_string()
+                    aria-valuenow=move || value(index).to_string()
+                    aria-orientation=move || orientation().orientation_name()
+                    aria-disabled=move || disabled().to_string()
+                    on:keydown=move |ev| on_keydown(index, ev)
+                >
+                    <span class="leptonic-slider-value">{move || value(index).to_string()}</span>
+                </div>
+            }
+        })
+        .collect_view(cx);
+
+    let slider_class = move || {
+        classes!(
+            "leptonic-slider",
+            marks
+                .with(|marks| marks.iter().any(|mark| mark.label.is_some()))
+                .then_some("leptonic-slider-labelled-marks")
+        )
+    };
+
+    view! { cx,
+        <div
+            class=slider_class
+            on:pointerdown=on_pointerdown
+            on:pointermove=on_pointermove
+            on:pointerup=stop_dragging
+            on:pointercancel=stop_dragging
+            on:lostpointercapture=stop_dragging
+        >
+            <div class="leptonic-slider-track"></div>
+            <div class="leptonic-slider-area" node_ref=area_ref>
+                <div class="leptonic-slider-fill" style=fill_style></div>
+                {mark_views}
+                {thumb_views}
+            </div>
+        </div>
+    }
+}
+
+/// Gets the class of a slider's container.
+fn container_class(
+    orientation: MaybeSignal<SliderOrientation>,
+    disabled: MaybeSignal<bool>,
+) -> impl Fn() -> String {
+    move || {
+        classes!(
+            "leptonic-slider-container",
+            format!(
+                "leptonic-slider-container-{}",
+                orientation.with(|o| o.orientation_name())
+            ),
+            disabled().then_some("leptonic-slider-container-disabled")
+        )
+    }
+}
+
+/// A slider for picking a number within a range. Set `min` and `max`, since
+/// the range otherwise covers every value of the number type.
+#[component]
+pub fn Slider<N>(
+    cx: Scope,
+    /// Slider state.
+    state: ReadSignal<N>,
+    /// Slider state setter.
+    set_state: WriteSignal<N>,
+    /// The minimum value.
+    #[prop(into, optional, default = MaybeSignal::Static(N::NUMBER_MIN))]
+    min: MaybeSignal<N>,
+    /// The maximum value.
+    #[prop(into, optional, default = MaybeSignal::Static(N::NUMBER_MAX))]
+    max: MaybeSignal<N>,
+    /// The amount the value changes by in each step.
+    #[prop(into, optional, default = MaybeSignal::Static(N::NUMBER_STEP))]
+    step: MaybeSignal<N>,
+    /// Slider label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// The direction the slider is laid out in.
+    #[prop(into, optional)]
+    orientation: MaybeSignal<SliderOrientation>,
+    /// Tick marks shown along the slider.
+    #[prop(into, optional)]
+    marks: MaybeSignal<Vec<SliderMark<N>>>,
+    /// Whether to show a number input for entering the value precisely.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    number_input: MaybeSignal<bool>,
+    /// Whether the slider is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    N: Number + 'static,
+{
+    let label_id = format!("{}-label", new_id());
+
+    let track = slider_track(
+        cx,
+        1,
+        move |_| state(),
+        move |_, value| set_state(value),
+        min,
+        max,
+        step,
+        label_id.clone(),
+        orientation,
+        marks,
+        disabled,
+    );
+    let input = move || {
+        number_input().then(|| {
+            view! { cx,
+                <NumberInput state set_state min max disabled />
+            }
+        })
+    };
+
+    view! { cx,
+        <div class=container_class(orientation, disabled)>
+            <span id=label_id class="leptonic-slider-label">{label}</span>
+            <div class="leptonic-slider-body">
+                {track}
+                <div class="leptonic-slider-inputs">{input}</div>
+            </div>
+        </div>
+    }
+}
+
+/// A slider with two thumbs for picking a range of numbers. Set `min` and
+/// `max`, since the range otherwise covers every value of the number type.
+#[component]
+pub fn RangeSlider<N>(
+    cx: Scope,
+    /// Range slider state, as the start and end of the range.
+    state: ReadSignal<(N, N)>,
+    /// Range slider state setter.
+    set_state: WriteSignal<(N, N)>,
+    /// The minimum value.
+    #[prop(into, optional, default = MaybeSignal::Static(N::NUMBER_MIN))]
+    min: MaybeSignal<N>,
+    /// The maximum value.
+    #[prop(into, optional, default = MaybeSignal::Static(N::NUMBER_MAX))]
+    max: MaybeSignal<N>,
+    /// The amount the values change by in each step.
+    #[prop(into, optional, default = MaybeSignal::Static(N::NUMBER_STEP))]
+    step: MaybeSignal<N>,
+    /// Range slider label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// The direction the slider is laid out in.
+    #[prop(into, optional)]
+    orientation: MaybeSignal<SliderOrientation>,
+    /// Tick marks shown along the slider.
+    #[prop(into, optional)]
+    marks: MaybeSignal<Vec<SliderMark<N>>>,
+    /// Whether to show number inputs for entering the values precisely.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    number_input: MaybeSignal<bool>,
+    /// Whether the range slider is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    N: Number + 'static,
+{
+    let label_id = format!("{}-label", new_id());
+
+    let value =
+        move |index: usize| state.with(|&(start, end)| if index == 0 { start } else { end });
+    // Moves one end of the range, without passing the other.
+    let set_value = move |index: usize, value: N| {
+        set_state.update(|(start, end)| {
+            if index == 0 {
+                *start = if value > *end { *end } else { value };
+            } else {
+                *end = if value < *start { *start } else { value };
+            }
+        });
+    };
+
+    // The number inputs edit each end of the range separately.
+    let (start, set_start) = create_signal(cx, state.get_untracked().0);
+    let (end, set_end) = create_signal(cx, state.get_untracked().1);
+    create_effect(cx, move |_| {
+        let (new_start, new_end) = state();
+        if start.get_untracked() != new_start {
+            set_start(new_start);
+        }
+        if end.get_untracked() != new_end {
+            set_end(new_end);
+        }
+    });
+    // Only the inputs are tracked, so that a stale input value is never
+    // written back over an outside change to the state.
+    create_effect(cx, move |_| {
+        let (new_start, new_end) = (start(), end());
+        if state.with_untracked(|&(start, _)| start) != new_start {
+            set_value(0, new_start);
+        }
+        if state.with_untracked(|&(_, end)| end) != new_end {
+            set_value(1, new_end);
+        }
+    });
+
+    let track = slider_track(
+        cx,
+        2,
+        value,
+        set_value,
+        min,
+        max,
+        step,
+        label_id.clone(),
+        orientation,
+        marks,
+        disabled,
+    );
+    let inputs = move || {
+        number_input().then(|| {
+            view! { cx,
+                <NumberInput state=start set_state=set_start min max disabled label="Minimum" />
+                <NumberInput state=end set_state=set_end min max disabled label="Maximum" />
+            }
+        })
+    };
+
+    view! { cx,
+        <div class=container_class(orientation, disabled)>
+            <span id=label_id class="leptonic-slider-label">{label}</span>
+            <div class="leptonic-slider-body">
+                {track}
+                <div class="leptonic-slider-inputs">{inputs}</div>
+            </div>
+        </div>
+    }
+}
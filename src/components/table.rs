@@ -0,0 +1,478 @@
+use super::checkbox::*;
+use super::pagination::*;
+use super::progress::*;
+use super::virtual_list::*;
+use crate::classes::*;
+use crate::table::*;
+use crate::util::*;
+use crate::virtualize::*;
+use leptos::ev::MouseEvent;
+use leptos::html::{Div, Tbody};
+use leptos::*;
+use std::cmp::Ordering;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// Renders the cell of a row.
+type CellRenderer<T> = Rc<dyn Fn(Scope, &T) -> View>;
+
+/// Orders two rows.
+type RowComparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// Gets a text from a row.
+type RowText<T> = Rc<dyn Fn(&T) -> String>;
+
+/// A column in a table.
+pub struct TableColumn<T> {
+    /// The ID of the column, used to refer to it when sorting and filtering.
+    pub id: String,
+    /// The column header.
+    pub header: String,
+    /// The width of the column, as a CSS length.
+    pub width: Option<String>,
+    /// Renders the cell of a row in this column.
+    pub cell: CellRenderer<T>,
+    /// Orders two rows by this column, if the column can be sorted.
+    pub compare: Option<RowComparator<T>>,
+    /// Gets the text a row is filtered by, if the column can be filtered.
+    pub filter_text: Option<RowText<T>>,
+}
+
+impl<T> TableColumn<T> {
+    /// Creates a new column.
+    pub fn new<V>(
+        id: impl Into<String>,
+        header: impl Into<String>,
+        cell: impl Fn(Scope, &T) -> V + 'static,
+    ) -> Self
+    where
+        V: IntoView,
+    {
+        Self {
+            id: id.into(),
+            header: header.into(),
+            width: None,
+            cell: Rc::new(move |cx, row| cell(cx, row).into_view(cx)),
+            compare: None,
+            filter_text: None,
+        }
+    }
+
+    /// Sets the width of the column, as a CSS length.
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Makes the column sortable by a key of each row.
+    pub fn sort_key<K: Ord>(mut self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.compare = Some(Rc::new(move |a, b| key(a).cmp(&key(b))));
+        self
+    }
+
+    /// Makes the column filterable by a text of each row. A row matches if
+    /// the text contains the filter query, ignoring case.
+    pub fn filter_text(mut self, text: impl Fn(&T) -> String + 'static) -> Self {
+        self.filter_text = Some(Rc::new(text));
+        self
+    }
+}
+
+/// A table of rows, with columns that can be sorted and filtered. Clicking a
+/// column header sorts by that column; shift-clicking sorts by it after the
+/// columns already sorted.
+#[component]
+pub fn Table<T, K, KF>(
+    cx: Scope,
+    /// The rows of the table.
+    #[prop(into)]
+    rows: MaybeSignal<Vec<T>>,
+    /// The columns of the table.
+    columns: Vec<TableColumn<T>>,
+    /// Gets a key identifying a row. A row's view is kept for as long as it
+    /// stays on the page and, when virtualized, in view.
+    row_key: KF,
+    /// The table caption.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// The number of rows on each page, or `None` to show every row.
+    #[prop(into, optional)]
+    page_size: MaybeSignal<Option<usize>>,
+    /// The selected rows. Rows can only be selected if this and
+    /// `set_selected` are both given.
+    #[prop(optional)]
+    selected: Option<ReadSignal<Vec<T>>>,
+    /// Selected rows setter.
+    #[prop(optional)]
+    set_selected: Option<WriteSignal<Vec<T>>>,
+    /// The height of each row, in pixels. When set, only the rows in view
+    /// are rendered, so that the table stays fast with many rows. Rows must
+    /// be rendered at exactly this height.
+    #[prop(into, optional)]
+    row_height: MaybeSignal<Option<f64>>,
+    /// Whether the rows are loading.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    loading: MaybeSignal<bool>,
+    /// Text shown when no rows match the filters.
+    #[prop(into, optional, default = MaybeSignal::Static("No results".to_owned()))]
+    empty_text: MaybeSignal<String>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+    K: Eq + Hash + 'static,
+    KF: Fn(&T) -> K + 'static,
+{
+    let column_count = columns.len();
+    let filterable = columns.iter().any(|column| column.filter_text.is_some());
+    let columns = store_value(cx, columns);
+    let rows = create_memo(cx, move |_| rows());
+    let selection = selected.zip(set_selected);
+    let (query, set_query) = create_signal(cx, TableQuery::default());
+    let (page, set_page) = create_signal(cx, 0);
+
+    let result = create_memo(cx, move |_| {
+        let query = query.with(|query| TableQuery {
+            page_size: page_size(),
+            ..query.clone()
+        });
+
+        rows.with(|rows| {
+            columns.with_value(|columns| {
+                let column = |id: &str| columns.iter().find(|column| column.id == id);
+
+                query.apply(
+                    rows,
+                    page(),
+                    |id, a, b| {
+                        column(id)
+                            .and_then(|column| column.compare.as_ref())
+                            .map_or(Ordering::Equal, |compare| compare(a, b))
+                    },
+                    |id, row, query| {
+                        column(id)
+                            .and_then(|column| column.filter_text.as_ref())
+                            .map_or(true, |text| text_matches(&text(row), query))
+                    },
+                )
+            })
+        })
+    });
+    let page_rows = create_memo(cx, move |_| {
+        result.with(|result| {
+            rows.with(|rows| {
+                result
+                    .indices
+                    .iter()
+                    .map(|&index| rows[index].clone())
+                    .collect::<Vec<_>>()
+            })
+        })
+    });
+
+    // Selecting every row selects the rows on the current page.
+    let select_all = selection.map(|(selected, set_selected)| {
+        let page_state = create_memo(cx, move |_| {
+            page_rows.with(|rows| {
+                selected.with(|values| {
+                    let checked = rows.iter().filter(|row| values.contains(row)).count();
+                    CheckboxState::from_counts(checked, rows.len())
+                })
+            })
+        });
+        let set_page = move |checked: bool| {
+            page_rows.with_untracked(|rows| {
+                set_selected.update(|values| {
+                    for row in rows {
+                        set_checked(values, row.clone(), checked);
+                    }
+                });
+            });
+        };
+
+        view! { cx,
+            <th class="leptonic-table-select" scope="col">
+                {checkbox_view(
+                    cx,
+                    Signal::derive(cx, move || page_state().is_checked()).into(),
+                    Signal::derive(cx, move || page_state() == CheckboxState::Indeterminate)
+                        .into(),
+                    move |_| set_page(page_state.get_untracked().toggled().is_checked()),
+                    MaybeSignal::Static("Select all rows".to_owned()),
+                    Signal::derive(cx, move || page_rows.with(Vec::is_empty)).into(),
+                )}
+            </th>
+        }
+    });
+
+    let headers = columns.with_value(|columns| {
+        columns
+            .iter()
+            .map(|column| {
+                let id = column.id.clone();
+                let style = column
+                    .width
+                    .as_ref()
+                    .map(|width| format!("width: {width};"));
+                let sort = {
+                    let id = id.clone();
+                    move || query.with(|query| query.sort_of(&id))
+                };
+                let multi_sort = move || query.with(|query| query.sorts.len() > 1);
+                let aria_sort = {
+                    let sort = sort.clone();
+                    move || {
+                        sort()
+                            .filter(|(priority, _)| *priority == 0)
+                            .map(|(_, direction)| direction.direction_name())
+                    }
+                };
+
+                if column.compare.is_none() {
+                    return view! { cx,
+                        <th scope="col" style=style>{column.header.clone()}</th>
+                    };
+                }
+
+                let icon_class = {
+                    let sort = sort.clone();
+                    move || {
+                        classes!(
+                            "leptonic-table-sort-icon",
+                            format!(
+                                "leptonic-table-sort-{}",
+                                sort().map_or("none", |(_, direction)| direction.direction_name())
+                            )
+                        )
+                    }
+                };
+                let priority = move || {
+                    sort().filter(|_| multi_sort()).map(|(priority, _)| {
+                        view! { cx,
+                            <span class="leptonic-table-sort-priority">{priority + 1}</span>
+                        }
+                    })
+                };
+
+                view! { cx,
+                    <th scope="col" style=style aria-sort=aria_sort>
+                        <button
+                            class="leptonic-table-sort"
+                            type="button"
+                            on:click=move |ev: MouseEvent| {
+                                set_query.update(|query| query.toggle_sort(&id, ev.shift_key()));
+                                set_page(0);
+                            }
+                        >
+                            {column.header.clone()}
+                            <span class=icon_class aria-hidden="true"></span>
+                            {priority}
+                        </button>
+                    </th>
+                }
+            })
+            .collect_view(cx)
+    });
+
+    let filters = filterable.then(|| {
+        let cells = columns.with_value(|columns| {
+            columns
+                .iter()
+                .map(|column| {
+                    let id = column.id.clone();
+                    let input = column.filter_text.is_some().then(|| {
+                        let value = {
+                            let id = id.clone();
+                            move || query.with(|query| query.filter_of(&id).to_owned())
+                        };
+
+                        view! { cx,
+                            <input
+                                class="leptonic-input leptonic-table-filter"
+                                type="search"
+                                placeholder="Filter"
+                                aria-label=format!("Filter by {}", column.header)
+                                prop:value=value
+                                on:input=move |ev| {
+                                    let value = input_event_value(&ev);
+                                    set_query.update(|query| query.set_filter(&id, value));
+                                    set_page(0);
+                                }
+                            />
+                        }
+                    });
+
+                    view! { cx, <td>{input}</td> }
+                })
+                .collect_view(cx)
+        });
+
+        view! { cx,
+            <tr class="leptonic-table-filters">
+                {selection.map(|_| view! { cx, <td></td> })}
+                {cells}
+            </tr>
+        }
+    });
+
+    let empty = move || {
+        page_rows.with(Vec::is_empty).then(|| {
+            let empty_text = empty_text.clone();
+
+            view! { cx,
+                <tr>
+                    <td
+                        class="leptonic-table-empty"
+                        colspan=column_count + usize::from(selection.is_some())
+                    >
+                        {move || (!loading()).then(|| empty_text.get())}
+                    </td>
+                </tr>
+            }
+        })
+    };
+
+    // Only the rows in view are rendered while virtualized. The table itself
+    // is the scrolling viewport, and the rows start below its header.
+    let container = create_node_ref::<Div>(cx);
+    let tbody = create_node_ref::<Tbody>(cx);
+    let layout = create_memo(cx, move |_| {
+        page_rows.with(|rows| ListLayout::fixed(rows.len(), row_height().unwrap_or_default()))
+    });
+    let (window, _) =
+        use_virtual_window(cx, container, layout, MaybeSignal::Static(5), move || {
+            tbody
+                .get_untracked()
+                .map_or(0.0, |tbody| f64::from(tbody.offset_top()))
+        });
+    let visible = move || {
+        if row_height().is_some() {
+            window()
+        } else {
+            0..page_rows.with(Vec::len)
+        }
+    };
+    let visible_rows = move || {
+        let range = visible();
+        page_rows.with(|rows| rows[range.start.min(rows.len())..range.end.min(rows.len())].to_vec())
+    };
+    let spacer = move |before: bool| {
+        move || {
+            row_height().map(|_| {
+                let range = visible();
+                let height = layout.with(|layout| {
+                    if before {
+                        layout.offset(range.start)
+                    } else {
+                        layout.total_height() - layout.offset(range.end)
+                    }
+                });
+
+                view! { cx,
+                    <tr class="leptonic-table-spacer" style=format!("height: {height}px;") aria-hidden="true"></tr>
+                }
+            })
+        }
+    };
+
+    let row_view = move |cx: Scope, row: T| {
+        let checked = selection.map(|(selected, _)| {
+            let row = row.clone();
+            Signal::derive(cx, move || selected.with(|values| values.contains(&row)))
+        });
+        let row_class = move || {
+            classes!(
+                "leptonic-table-row",
+                checked
+                    .map_or(false, |checked| checked())
+                    .then_some("leptonic-table-row-selected")
+            )
+        };
+        let row_style = move || row_height().map(|height| format!("height: {height}px;"));
+        let select = selection.zip(checked).map(|((_, set_selected), checked)| {
+            let row = row.clone();
+
+            view! { cx,
+                <td class="leptonic-table-select">
+                    {checkbox_view(
+                        cx,
+                        checked.into(),
+                        MaybeSignal::Static(false),
+                        move |checked| {
+                            set_selected.update(|values| set_checked(values, row.clone(), checked))
+                        },
+                        MaybeSignal::Static("Select row".to_owned()),
+                        MaybeSignal::Static(false),
+                    )}
+                </td>
+            }
+        });
+        let cells = columns.with_value(|columns| {
+            columns
+                .iter()
+                .map(|column| view! { cx, <td>{(column.cell)(cx, &row)}</td> })
+                .collect_view(cx)
+        });
+
+        view! { cx,
+            <tr class=row_class style=row_style>
+                {select}
+                {cells}
+            </tr>
+        }
+    };
+
+    let pagination = move || {
+        page_size().map(|page_size| {
+            view! { cx,
+                <div class="leptonic-table-pagination">
+                    <Pagination
+                        page
+                        set_page
+                        total=Signal::derive(cx, move || result.with(|result| result.total))
+                        page_size
+                    />
+                </div>
+            }
+        })
+    };
+
+    let caption = move || {
+        label.with(|label| {
+            (!label.is_empty()).then(|| {
+                view! { cx, <caption class="leptonic-table-caption">{label.clone()}</caption> }
+            })
+        })
+    };
+
+    view! { cx,
+        <LoadingOverlay loading=loading>
+            <div
+                class=move || {
+                    classes!(
+                        "leptonic-table-container",
+                        row_height().is_some().then_some("leptonic-table-container-virtual")
+                    )
+                }
+                node_ref=container
+            >
+                <table class="leptonic-table">
+                    {caption}
+                    <thead>
+                        <tr>
+                            {select_all}
+                            {headers}
+                        </tr>
+                        {filters}
+                    </thead>
+                    <tbody node_ref=tbody>
+                        {empty}
+                        {spacer(true)}
+                        <For each=visible_rows key=row_key view=row_view />
+                        {spacer(false)}
+                    </tbody>
+                </table>
+            </div>
+            {pagination}
+        </LoadingOverlay>
+    }
+}
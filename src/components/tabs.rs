@@ -0,0 +1,488 @@
+use super::popover::*;
+use crate::classes::*;
+use crate::position::*;
+use crate::util::*;
+use leptos::ev::KeyboardEvent;
+use leptos::html::{Button, Div};
+use leptos::*;
+use leptos_use::use_event_listener;
+use std::time::Duration;
+
+/// When the panels of a set of tabs are rendered.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TabRendering {
+    /// Only the selected panel is rendered. Other panels are removed from the
+    /// page, along with their state.
+    #[default]
+    Lazy,
+    /// Every panel is rendered up front, and unselected panels are hidden.
+    Eager,
+}
+
+impl TabRendering {
+    /// Gets the name of the rendering mode.
+    pub fn rendering_name(&self) -> &'static str {
+        match *self {
+            Self::Lazy => "lazy",
+            Self::Eager => "eager",
+        }
+    }
+}
+
+/// What happens when a set of tabs is too wide to fit.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TabsOverflow {
+    /// The tabs scroll horizontally.
+    #[default]
+    Scroll,
+    /// The tabs that do not fit are collapsed into a menu.
+    Menu,
+}
+
+impl TabsOverflow {
+    /// Gets the name of the overflow mode.
+    pub fn overflow_name(&self) -> &'static str {
+        match *self {
+            Self::Scroll => "scroll",
+            Self::Menu => "menu",
+        }
+    }
+}
+
+/// A tab registered with a set of tabs.
+#[derive(Clone)]
+struct TabEntry {
+    /// The key identifying the tab.
+    key: String,
+    /// The tab label.
+    label: MaybeSignal<String>,
+    /// Whether the tab is disabled.
+    disabled: MaybeSignal<bool>,
+    /// The ID of the tab element.
+    id: String,
+}
+
+impl TabEntry {
+    /// Gets the ID of the tab's panel.
+    fn panel_id(&self) -> String {
+        format!("{}-panel", self.id)
+    }
+}
+
+/// State shared between a set of tabs and its tabs and panels.
+#[derive(Clone, Copy)]
+struct TabsContext {
+    /// The key of the selected tab.
+    selected: ReadSignal<String>,
+    /// The tabs, in order.
+    tabs: RwSignal<Vec<TabEntry>>,
+    /// When panels are rendered by default.
+    rendering: MaybeSignal<TabRendering>,
+}
+
+impl TabsContext {
+    /// Gets the context provided by the enclosing [`Tabs`].
+    fn expect(cx: Scope) -> Self {
+        use_context::<Self>(cx).expect("tabs and tab panels must be placed within `Tabs`")
+    }
+
+    /// Finds a registered tab by its key.
+    fn tab(&self, key: &str) -> Option<TabEntry> {
+        self.tabs
+            .with(|tabs| tabs.iter().find(|tab| tab.key == key).cloned())
+    }
+}
+
+/// A set of tabs, each showing a different panel. Place a [`Tab`] and a
+/// [`TabPanel`] with a matching key within it for each tab.
+#[component]
+pub fn Tabs(
+    cx: Scope,
+    /// The key of the selected tab.
+    state: ReadSignal<String>,
+    /// Selected tab setter.
+    set_state: WriteSignal<String>,
+    /// An accessible label for the set of tabs.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// When panels are rendered, unless overridden by a panel.
+    #[prop(into, optional)]
+    rendering: MaybeSignal<TabRendering>,
+    /// What happens when the tabs are too wide to fit.
+    #[prop(into, optional)]
+    overflow: MaybeSignal<TabsOverflow>,
+    /// The tabs and their panels.
+    children: Children,
+) -> impl IntoView {
+    let context = TabsContext {
+        selected: state,
+        tabs: create_rw_signal(cx, Vec::new()),
+        rendering,
+    };
+    provide_context(cx, context);
+
+    // Render the children first, so that every tab is registered.
+    let panels = children(cx);
+
+    let tabs = context.tabs;
+    let (overflowed, set_overflowed) = create_signal(cx, Vec::<String>::new());
+    let (menu_open, set_menu_open) = create_signal(cx, false);
+    let list_ref = create_node_ref::<Div>(cx);
+    let menu_button_ref = create_node_ref::<Button>(cx);
+    let menu_ref = create_node_ref::<Div>(cx);
+
+    let is_enabled = move |index: usize| {
+        tabs.with(|tabs| tabs.get(index).map_or(false, |tab| !tab.disabled.get()))
+    };
+    let tab_count = move || tabs.with(|tabs| tabs.len());
+    let selected_index =
+        move || state.with(|key| tabs.with(|tabs| tabs.iter().position(|tab| tab.key == *key)));
+
+    let select_index = move |index: usize| {
+        if let Some(tab) = tabs.with(|tabs| tabs.get(index).cloned()) {
+            if !tab.disabled.get() {
+                set_state(tab.key);
+                focus_by_id(tab.id);
+            }
+        }
+    };
+
+    // Select the first enabled tab if the selected key matches none.
+    create_effect(cx, move |_| {
+        if selected_index().map_or(true, |index| !is_enabled(index)) {
+            if let Some(index) = first_enabled_index(tab_count(), is_enabled) {
+                if let Some(key) = tabs.with(|tabs| tabs.get(index).map(|tab| tab.key.clone())) {
+                    set_state(key);
+                }
+            }
+        }
+    });
+
+    // Tabs collapsed into the menu are reached through the menu instead.
+    let is_navigable = move |index: usize| {
+        is_enabled(index)
+            && tabs.with(|tabs| {
+                tabs.get(index).map_or(false, |tab| {
+                    overflowed.with(|overflowed| !overflowed.contains(&tab.key))
+                })
+            })
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let rtl = is_rtl();
+        let target = match ev.key().as_str() {
+            "ArrowLeft" | "ArrowRight" => {
+                let forward = (ev.key() == "ArrowRight") != rtl;
+                step_enabled_index(tab_count(), selected_index(), forward, true, is_navigable)
+            }
+            "Home" => first_enabled_index(tab_count(), is_navigable),
+            "End" => last_enabled_index(tab_count(), is_navigable),
+            _ => return,
+        };
+
+        ev.prevent_default();
+
+        if let Some(index) = target {
+            select_index(index);
+        }
+    };
+
+    // Find the tabs that do not fit, when collapsing them into a menu.
+    let measure = move || {
+        let Some(list) = list_ref.get_untracked() else {
+            return;
+        };
+
+        let hidden = if overflow.get_untracked() == TabsOverflow::Menu {
+            // Tabs overflow past the end of the list, which is on the left
+            // when laying out right to left.
+            let rtl = is_rtl();
+            let bounds = list.get_bounding_client_rect();
+            tabs.with_untracked(|tabs| {
+                tabs.iter()
+                    .filter(|tab| {
+                        document()
+                            .get_element_by_id(&tab.id)
+                            .map_or(false, |element| {
+                                let rect = element.get_bounding_client_rect();
+
+                                if rtl {
+                                    rect.left() < bounds.left()
+                                } else {
+                                    rect.right() > bounds.right()
+                                }
+                            })
+                    })
+                    .map(|tab| tab.key.clone())
+                    .collect()
+            })
+        } else {
+            Vec::new()
+        };
+
+        if overflowed.with_untracked(|overflowed| *overflowed != hidden) {
+            set_overflowed(hidden);
+        }
+    };
+
+    create_effect(cx, move |_| {
+        tabs.track();
+        overflow.track();
+        request_animation_frame(measure);
+    });
+    _ = use_event_listener(cx, window(), ev::resize, move |_| measure());
+
+    // Keep the selected tab in view when scrolling.
+    create_effect(cx, move |_| {
+        if overflow() == TabsOverflow::Scroll {
+            if let Some(index) = selected_index() {
+                if let Some(id) = tabs.with(|tabs| tabs.get(index).map(|tab| tab.id.clone())) {
+                    request_animation_frame(move || scroll_into_view(&id));
+                }
+            }
+        }
+    });
+
+    let tab_views = move || {
+        tabs.get()
+            .into_iter()
+            .map(|tab| {
+                let key = tab.key.clone();
+                let selected = {
+                    let key = key.clone();
+                    move || state.with(|selected| *selected == key)
+                };
+                let is_overflowed = {
+                    let key = key.clone();
+                    move || overflowed.with(|overflowed| overflowed.contains(&key))
+                };
+                let disabled = tab.disabled;
+                let tab_class = {
+                    let selected = selected.clone();
+                    move || {
+                        classes!(
+                            "leptonic-tab",
+                            selected().then_some("leptonic-tab-selected"),
+                            disabled().then_some("leptonic-tab-disabled"),
+                            is_overflowed().then_some("leptonic-tab-overflowed")
+                        )
+                    }
+                };
+                let tabindex = {
+                    let selected = selected.clone();
+                    move || if selected() { "0" } else { "-1" }
+                };
+
+                view! { cx,
+                    <button
+                        id=tab.id.clone()
+                        class=tab_class
+                        type="button"
+                        role="tab"
+                        tabindex=tabindex
+                        aria-selected=move || selected().to_string()
+                        aria-controls=tab.panel_id()
+                        aria-disabled=move || disabled().to_string()
+                        on:click=move |_| {
+                            if !tab.disabled.get() {
+                                set_state(key.clone());
+                            }
+                        }
+                    >
+                        {tab.label.clone()}
+                    </button>
+                }
+            })
+            .collect_view(cx)
+    };
+
+    let menu = move || {
+        (overflow() == TabsOverflow::Menu && overflowed.with(|overflowed| !overflowed.is_empty()))
+            .then(|| {
+                let menu_button_class = move || {
+                    classes!(
+                        "leptonic-tabs-menu-button",
+                        overflowed
+                            .with(|overflowed| state.with(|selected| overflowed.contains(selected)))
+                            .then_some("leptonic-tabs-menu-button-selected")
+                    )
+                };
+
+                view! { cx,
+                    <button
+                        class=menu_button_class
+                        type="button"
+                        aria-haspopup="true"
+                        aria-expanded=move || menu_open().to_string()
+                        node_ref=menu_button_ref
+                    >
+                        {move || format!("{} more", overflowed.with(|overflowed| overflowed.len()))}
+                        <span class="leptonic-tabs-menu-icon"></span>
+                    </button>
+                }
+            })
+    };
+
+    use_floating_trigger(
+        cx,
+        menu_button_ref,
+        menu_ref,
+        menu_open,
+        set_menu_open,
+        MaybeSignal::Static(FloatingTrigger::Click),
+        MaybeSignal::Static(Duration::ZERO),
+    );
+
+    let menu_popup = move || {
+        let items = overflowed
+            .get()
+            .into_iter()
+            .filter_map(|key| context.tab(&key))
+            .map(|tab| {
+                let key = tab.key.clone();
+                let disabled = tab.disabled;
+                let selected = {
+                    let key = key.clone();
+                    move || state.with(|selected| *selected == key)
+                };
+                let item_class = move || {
+                    classes!(
+                        "leptonic-tabs-overflow-menu-item",
+                        selected().then_some("leptonic-tabs-overflow-menu-item-selected")
+                    )
+                };
+
+                view! { cx,
+                    <button
+                        class=item_class
+                        type="button"
+                        disabled=disabled
+                        on:click=move |_| {
+                            set_state(key.clone());
+                            set_menu_open(false);
+                        }
+                    >
+                        {tab.label.clone()}
+                    </button>
+                }
+            })
+            .collect_view(cx);
+
+        view! { cx,
+            <div class="leptonic-tabs-overflow-menu" node_ref=menu_ref>
+                {items}
+            </div>
+        }
+    };
+
+    floating_layer(
+        cx,
+        menu_button_ref,
+        menu_ref,
+        menu_open,
+        MaybeSignal::Static(Placement::BOTTOM_END),
+        PositionOptions::default().offset(4.0),
+        menu_popup,
+    );
+
+    let tabs_class = move || {
+        classes!(
+            "leptonic-tabs",
+            format!("leptonic-tabs-{}", overflow.with(|o| o.overflow_name()))
+        )
+    };
+
+    view! { cx,
+        <div class=tabs_class>
+            <div class="leptonic-tab-bar">
+                <div
+                    class="leptonic-tab-list"
+                    role="tablist"
+                    aria-label=move || label.with(|label| (!label.is_empty()).then(|| label.clone()))
+                    node_ref=list_ref
+                    on:keydown=on_keydown
+                >
+                    {tab_views}
+                </div>
+                {menu}
+            </div>
+            {panels}
+        </div>
+    }
+}
+
+/// A tab within a set of [`Tabs`]. The tab is shown in the set's tab list,
+/// wherever it is placed within the set.
+#[component]
+pub fn Tab(
+    cx: Scope,
+    /// The key identifying the tab, matching the key of its panel.
+    #[prop(into)]
+    key: String,
+    /// The tab label.
+    #[prop(into)]
+    label: MaybeSignal<String>,
+    /// Whether the tab is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    let context = TabsContext::expect(cx);
+    let entry = TabEntry {
+        key: key.clone(),
+        label,
+        disabled,
+        id: new_id(),
+    };
+
+    context.tabs.update(|tabs| tabs.push(entry));
+    on_cleanup(cx, move || {
+        context
+            .tabs
+            .update(|tabs| tabs.retain(|tab| tab.key != key));
+    });
+}
+
+/// The panel shown while a [`Tab`] with the same key is selected.
+#[component]
+pub fn TabPanel(
+    cx: Scope,
+    /// The key of the panel's tab.
+    #[prop(into)]
+    key: String,
+    /// When the panel is rendered, overriding the setting of the set of tabs.
+    #[prop(into, optional)]
+    rendering: Option<MaybeSignal<TabRendering>>,
+    /// The panel content.
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = TabsContext::expect(cx);
+    let rendering = rendering.unwrap_or(context.rendering);
+    let tab = {
+        let key = key.clone();
+        create_memo(cx, move |_| {
+            context
+                .tab(&key)
+                .map(|tab| (tab.id.clone(), tab.panel_id()))
+        })
+    };
+    let selected = create_memo(cx, move |_| {
+        context.selected.with(|selected| *selected == key)
+    });
+    let rendered = create_memo(cx, move |_| {
+        selected() || rendering() == TabRendering::Eager
+    });
+    let content = move || rendered().then(|| children(cx));
+
+    view! { cx,
+        <div
+            id=move || tab().map(|(_, panel_id)| panel_id)
+            class="leptonic-tab-panel"
+            role="tabpanel"
+            tabindex="0"
+            aria-labelledby=move || tab().map(|(tab_id, _)| tab_id)
+            hidden=move || !selected()
+        >
+            {content}
+        </div>
+    }
+}
@@ -0,0 +1,408 @@
+use super::error::*;
+use crate::classes::*;
+use crate::util::*;
+use chrono::{NaiveTime, Timelike};
+use leptos::ev::KeyboardEvent;
+use leptos::*;
+
+/// How long to wait for a second digit to be typed into a time segment, in
+/// milliseconds.
+const DIGIT_TIMEOUT_MS: f64 = 1000.0;
+
+/// The clock used to display hours.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum HourFormat {
+    /// A 12-hour clock with an AM/PM period.
+    H12,
+    /// A 24-hour clock.
+    #[default]
+    H24,
+}
+
+impl HourFormat {
+    /// Gets the name of the hour format.
+    pub fn format_name(&self) -> &'static str {
+        match *self {
+            Self::H12 => "12h",
+            Self::H24 => "24h",
+        }
+    }
+}
+
+/// Converts an hour on a 24-hour clock to an hour on a 12-hour clock, along
+/// with whether it is in the afternoon.
+///
+/// ```
+/// # use leptonic::{from_12_hour, to_12_hour};
+/// assert_eq!(to_12_hour(0), (12, false));
+/// assert_eq!(to_12_hour(13), (1, true));
+/// assert_eq!(from_12_hour(12, false), 0);
+/// assert_eq!(from_12_hour(12, true), 12);
+/// ```
+pub fn to_12_hour(hour: u32) -> (u32, bool) {
+    let pm = hour >= 12;
+    match hour % 12 {
+        0 => (12, pm),
+        hour => (hour, pm),
+    }
+}
+
+/// Converts an hour on a 12-hour clock to an hour on a 24-hour clock.
+pub fn from_12_hour(hour: u32, pm: bool) -> u32 {
+    hour % 12 + if pm { 12 } else { 0 }
+}
+
+/// An editable part of a time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TimeSegment {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+impl TimeSegment {
+    /// Gets the name of the segment.
+    fn segment_name(&self) -> &'static str {
+        match *self {
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Second => "second",
+            Self::Period => "period",
+        }
+    }
+
+    /// Gets the accessible label of the segment.
+    fn label(&self) -> &'static str {
+        match *self {
+            Self::Hour => "Hours",
+            Self::Minute => "Minutes",
+            Self::Second => "Seconds",
+            Self::Period => "AM/PM",
+        }
+    }
+
+    /// Gets the inclusive range of values the segment can take.
+    fn range(&self, hour_format: HourFormat) -> (u32, u32) {
+        match (*self, hour_format) {
+            (Self::Hour, HourFormat::H12) => (1, 12),
+            (Self::Hour, HourFormat::H24) => (0, 23),
+            (Self::Minute | Self::Second, _) => (0, 59),
+            (Self::Period, _) => (0, 1),
+        }
+    }
+
+    /// Gets the segment's value in a time.
+    fn value(&self, time: NaiveTime, hour_format: HourFormat) -> u32 {
+        match (*self, hour_format) {
+            (Self::Hour, HourFormat::H12) => to_12_hour(time.hour()).0,
+            (Self::Hour, HourFormat::H24) => time.hour(),
+            (Self::Minute, _) => time.minute(),
+            (Self::Second, _) => time.second(),
+            (Self::Period, _) => to_12_hour(time.hour()).1 as u32,
+        }
+    }
+
+    /// Sets the segment's value in a time.
+    fn with_value(&self, time: NaiveTime, value: u32, hour_format: HourFormat) -> NaiveTime {
+        let updated = match (*self, hour_format) {
+            (Self::Hour, HourFormat::H12) => {
+                time.with_hour(from_12_hour(value, to_12_hour(time.hour()).1))
+            }
+            (Self::Hour, HourFormat::H24) => time.with_hour(value),
+            (Self::Minute, _) => time.with_minute(value),
+            (Self::Second, _) => time.with_second(value),
+            (Self::Period, _) => {
+                time.with_hour(from_12_hour(to_12_hour(time.hour()).0, value == 1))
+            }
+        };
+
+        updated.unwrap_or(time)
+    }
+
+    /// Formats the segment's value in a time.
+    fn display(&self, time: Option<NaiveTime>, hour_format: HourFormat) -> String {
+        match (time, *self) {
+            (Some(time), Self::Period) => match self.value(time, hour_format) {
+                1 => "PM".to_owned(),
+                _ => "AM".to_owned(),
+            },
+            (Some(time), _) => format!("{:02}", self.value(time, hour_format)),
+            (None, _) => "--".to_owned(),
+        }
+    }
+}
+
+/// Steps a value within an inclusive range by a step size, wrapping around
+/// at either end. Values that are not a multiple of the step from the start
+/// of the range snap to the adjacent multiple.
+fn step_value(value: u32, (min, max): (u32, u32), step: u32, forward: bool) -> u32 {
+    let step = step.max(1);
+    let offset = value.clamp(min, max) - min;
+    let last = (max - min) / step * step;
+
+    let offset = if forward {
+        let next = offset / step * step + step;
+        if next > last {
+            0
+        } else {
+            next
+        }
+    } else if offset % step != 0 {
+        offset / step * step
+    } else if offset == 0 {
+        last
+    } else {
+        offset - step
+    };
+
+    min + offset
+}
+
+/// A time picker element.
+#[component]
+pub fn TimePicker(
+    cx: Scope,
+    /// Time picker state.
+    state: ReadSignal<Option<NaiveTime>>,
+    /// Time picker state setter.
+    set_state: WriteSignal<Option<NaiveTime>>,
+    /// Time picker label.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+    /// The clock used to display hours.
+    #[prop(into, optional)]
+    hour_format: MaybeSignal<HourFormat>,
+    /// The number of minutes stepped by the arrow keys.
+    #[prop(into, optional, default = MaybeSignal::Static(1))]
+    minute_step: MaybeSignal<u32>,
+    /// Whether seconds can be chosen.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    seconds: MaybeSignal<bool>,
+    /// Whether the time picker is required.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    required: MaybeSignal<bool>,
+    /// Whether the time picker is disabled.
+    #[prop(into, optional, default = MaybeSignal::Static(false))]
+    disabled: MaybeSignal<bool>,
+    /// An optional error message.
+    #[prop(into, optional)]
+    error: MaybeSignal<Option<String>>,
+) -> impl IntoView {
+    let id = new_id();
+    let segment_id = {
+        let id = id.clone();
+        move |segment: TimeSegment| format!("{id}-{}", segment.segment_name())
+    };
+
+    // The first digit typed into a segment, and when it was typed.
+    let pending_digit = store_value(cx, None::<(TimeSegment, u32, f64)>);
+
+    let segments = move || {
+        let mut segments = vec![TimeSegment::Hour, TimeSegment::Minute];
+
+        if seconds() {
+            segments.push(TimeSegment::Second);
+        }
+
+        if hour_format() == HourFormat::H12 {
+            segments.push(TimeSegment::Period);
+        }
+
+        segments
+    };
+
+    // Clear seconds when they can no longer be chosen.
+    create_effect(cx, move |_| {
+        if !seconds() {
+            if let Some(time) = state.get_untracked().filter(|time| time.second() != 0) {
+                set_state(time.with_second(0));
+            }
+        }
+    });
+
+    let set_segment = move |segment: TimeSegment, value: u32| {
+        let time = state().unwrap_or_default();
+        set_state(Some(segment.with_value(time, value, hour_format())));
+    };
+    let focus_segment = {
+        let segment_id = segment_id.clone();
+        move |current: TimeSegment, forward: bool| {
+            let segments = segments();
+            let index = segments.iter().position(|&segment| segment == current);
+            let target = match (index, forward) {
+                (Some(index), true) => segments.get(index + 1),
+                (Some(index), false) => index.checked_sub(1).and_then(|i| segments.get(i)),
+                (None, _) => None,
+            };
+
+            if let Some(&target) = target {
+                focus_by_id(segment_id(target));
+            }
+        }
+    };
+
+    let on_segment_keydown = move |segment: TimeSegment, ev: KeyboardEvent| {
+        if disabled() {
+            return;
+        }
+
+        let format = hour_format();
+        let range = segment.range(format);
+        let current = state().map(|time| segment.value(time, format));
+        let step = if segment == TimeSegment::Minute {
+            minute_step()
+        } else {
+            1
+        };
+        let rtl = is_rtl();
+
+        match ev.key().as_str() {
+            "ArrowUp" | "ArrowDown" => {
+                ev.prevent_default();
+                let forward = ev.key() == "ArrowUp";
+                let value = match current {
+                    Some(value) => step_value(value, range, step, forward),
+                    None if forward => range.0,
+                    None => step_value(range.0, range, step, false),
+                };
+                set_segment(segment, value);
+            }
+            "ArrowLeft" | "ArrowRight" => {
+                ev.prevent_default();
+                focus_segment(segment, (ev.key() == "ArrowRight") != rtl);
+            }
+            "Backspace" | "Delete" => {
+                ev.prevent_default();
+                set_state(None);
+            }
+            key if segment == TimeSegment::Period => match key {
+                "a" | "A" => set_segment(segment, 0),
+                "p" | "P" => set_segment(segment, 1),
+                _ => {}
+            },
+            key => {
+                if let Some(digit) = key.chars().next().and_then(|chr| chr.to_digit(10)) {
+                    if key.len() != 1 {
+                        return;
+                    }
+
+                    ev.prevent_default();
+                    let now = js_sys::Date::now();
+                    let pending = pending_digit.with_value(|pending| {
+                        pending.filter(|&(pending_segment, _, time)| {
+                            pending_segment == segment && now - time < DIGIT_TIMEOUT_MS
+                        })
+                    });
+                    let (min, max) = range;
+
+                    let (value, complete) = match pending {
+                        Some((_, first, _)) if first * 10 + digit <= max => {
+                            (first * 10 + digit, true)
+                        }
+                        _ => (digit, digit * 10 > max),
+                    };
+                    let value = if segment == TimeSegment::Minute {
+                        value / step.max(1) * step.max(1)
+                    } else {
+                        value
+                    };
+
+                    if value >= min {
+                        set_segment(segment, value);
+                    }
+
+                    if complete {
+                        pending_digit.set_value(None);
+                        focus_segment(segment, true);
+                    } else {
+                        pending_digit.set_value(Some((segment, digit, now)));
+                    }
+                }
+            }
+        }
+    };
+
+    let segment_views = {
+        let segment_id = segment_id.clone();
+        move || {
+            let segments = segments();
+            segments
+                .iter()
+                .enumerate()
+                .map(|(index, &segment)| {
+                    let on_segment_keydown = on_segment_keydown.clone();
+                    let (min, max) = segment.range(hour_format());
+                    let text = move || segment.display(state(), hour_format());
+                    let value_now = move || state().map(|time| segment.value(time, hour_format()));
+                    let separator = segments.get(index + 1).map(|&next| {
+                        let separator = if next == TimeSegment::Period { " " } else { ":" };
+
+                        view! { cx,
+                            <span class="leptonic-time-picker-separator" aria-hidden="true">
+                                {separator}
+                            </span>
+                        }
+                    });
+
+                    view! { cx,
+                        <span
+                            id=segment_id(segment)
+                            class=format!("leptonic-time-picker-segment leptonic-time-picker-segment-{}", segment.segment_name())
+                            role="spinbutton"
+                            tabindex=move || if disabled() { "-1" } else { "0" }
+                            aria-label=segment.label()
+                            aria-valuemin=min
+                            aria-valuemax=max
+                            aria-valuenow=value_now
+                            aria-valuetext=text
+                            aria-disabled=move || disabled().to_string()
+                            on:keydown=move |ev| on_segment_keydown(segment, ev)
+                            on:blur=move |_| pending_digit.set_value(None)
+                        >
+                            {text}
+                        </span>
+                        {separator}
+                    }
+                })
+                .collect_view(cx)
+        }
+    };
+
+    let container_class = move || {
+        classes!(
+            "leptonic-time-picker-container",
+            disabled().then_some("leptonic-time-picker-container-disabled")
+        )
+    };
+    let field_class = {
+        let error = error.clone();
+        move || {
+            classes!(
+                "leptonic-time-picker",
+                format!("leptonic-time-picker-{}", hour_format().format_name()),
+                state().is_none().then_some("leptonic-time-picker-empty"),
+                error().map(|_| "leptonic-time-picker-invalid")
+            )
+        }
+    };
+    let label_id = format!("{id}-label");
+
+    view! { cx,
+        <div class=container_class>
+            <label id=label_id.clone() class="leptonic-time-picker-label">
+                {label}
+                <span class="leptonic-required-mark">{required().then_some(" *").unwrap_or_default()}</span>
+            </label>
+            <div
+                class=field_class
+                role="group"
+                aria-labelledby=label_id
+                aria-required=move || required().to_string()
+            >
+                {segment_views}
+            </div>
+            <Error message=error size=ErrorSize::Small />
+        </div>
+    }
+}
@@ -0,0 +1,603 @@
+use crate::classes::*;
+use leptos::*;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// How long toasts are shown for by default.
+pub const DEFAULT_TOAST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The kind of a toast, which determines its color and how urgently it is
+/// announced to assistive technologies.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ToastVariant {
+    /// A successful outcome.
+    Success,
+    /// General information.
+    #[default]
+    Info,
+    /// Something that may need attention.
+    Warning,
+    /// A failure.
+    Error,
+}
+
+impl ToastVariant {
+    /// Gets the name of the toast variant.
+    pub fn variant_name(&self) -> &'static str {
+        match *self {
+            Self::Success => "success",
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+
+    /// Whether toasts of this variant interrupt assistive technologies.
+    fn is_urgent(&self) -> bool {
+        matches!(*self, Self::Warning | Self::Error)
+    }
+}
+
+/// The size of a toast's text.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ToastSize {
+    /// Very small text.
+    Smaller,
+    /// Small text.
+    Small,
+    /// Medium sized text.
+    #[default]
+    Medium,
+    /// Large text.
+    Large,
+    /// Very large text.
+    Larger,
+}
+
+impl ToastSize {
+    /// Gets the name of the toast size.
+    pub fn size_name(&self) -> &'static str {
+        match *self {
+            Self::Smaller => "smaller",
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+            Self::Larger => "larger",
+        }
+    }
+}
+
+/// The corner of the screen in which toasts are shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ToastPosition {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+impl ToastPosition {
+    /// Gets the name of the toast position.
+    pub fn position_name(&self) -> &'static str {
+        match *self {
+            Self::TopLeft => "top-left",
+            Self::TopRight => "top-right",
+            Self::BottomLeft => "bottom-left",
+            Self::BottomRight => "bottom-right",
+        }
+    }
+}
+
+/// A button in a toast. Clicking it also dismisses the toast.
+#[derive(Clone)]
+pub struct ToastAction {
+    /// The text on the button.
+    pub text: String,
+    /// The button click callback.
+    pub on_click: Rc<dyn Fn()>,
+}
+
+impl ToastAction {
+    /// Creates a new toast action.
+    pub fn new(text: impl Into<String>, on_click: impl Fn() + 'static) -> Self {
+        Self {
+            text: text.into(),
+            on_click: Rc::new(on_click),
+        }
+    }
+}
+
+/// A toast notification.
+#[derive(Clone)]
+pub struct Toast {
+    /// The toast variant.
+    pub variant: ToastVariant,
+    /// An optional title, shown above the message.
+    pub title: Option<String>,
+    /// The toast message.
+    pub message: String,
+    /// How long the toast is shown for, or `None` to show it until it is
+    /// dismissed.
+    pub timeout: Option<Duration>,
+    /// The buttons in the toast.
+    pub actions: Vec<ToastAction>,
+    /// The size of the toast's text.
+    pub size: ToastSize,
+    /// The key used to deduplicate the toast, or `None` to deduplicate by
+    /// variant, title and message.
+    pub key: Option<String>,
+}
+
+impl Toast {
+    /// Creates a new toast.
+    pub fn new(variant: ToastVariant, message: impl Into<String>) -> Self {
+        Self {
+            variant,
+            title: None,
+            message: message.into(),
+            timeout: Some(DEFAULT_TOAST_TIMEOUT),
+            actions: Vec::new(),
+            size: ToastSize::default(),
+            key: None,
+        }
+    }
+
+    /// Creates a new success toast.
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(ToastVariant::Success, message)
+    }
+
+    /// Creates a new info toast.
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(ToastVariant::Info, message)
+    }
+
+    /// Creates a new warning toast.
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(ToastVariant::Warning, message)
+    }
+
+    /// Creates a new error toast.
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(ToastVariant::Error, message)
+    }
+
+    /// Sets the toast title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets how long the toast is shown for.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Shows the toast until it is dismissed.
+    pub fn persistent(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    /// Adds a button to the toast.
+    pub fn action(mut self, text: impl Into<String>, on_click: impl Fn() + 'static) -> Self {
+        self.actions.push(ToastAction::new(text, on_click));
+        self
+    }
+
+    /// Sets the size of the toast's text.
+    pub fn size(mut self, size: ToastSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the key used to deduplicate the toast.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Gets the key used to deduplicate the toast.
+    fn dedupe_key(&self) -> String {
+        match &self.key {
+            Some(key) => key.clone(),
+            None => format!(
+                "{}\n{}\n{}",
+                self.variant.variant_name(),
+                self.title.as_deref().unwrap_or_default(),
+                self.message
+            ),
+        }
+    }
+}
+
+/// Identifies a toast that has been shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+/// A toast being shown.
+#[derive(Clone)]
+struct ShownToast {
+    /// The toast's ID.
+    id: ToastId,
+    /// The toast.
+    toast: Toast,
+    /// The key used to deduplicate the toast.
+    key: String,
+    /// The number of times the toast has been shown.
+    count: RwSignal<usize>,
+}
+
+/// A reason for pausing a toast's timer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pause {
+    Hover,
+    Focus,
+}
+
+/// The auto-dismiss timer of a toast.
+#[derive(Debug, Clone, Copy, Default)]
+struct ToastTimer {
+    /// Incremented whenever the timer is restarted or paused, which cancels
+    /// any pending timeout.
+    generation: usize,
+    /// The time left before the toast is dismissed, or `None` if the toast is
+    /// shown until it is dismissed.
+    remaining: Option<Duration>,
+    /// When the timer was last started, or `None` if it is not running.
+    started: Option<f64>,
+    /// Whether the toast is hovered.
+    hovered: bool,
+    /// Whether the toast has focus.
+    focused: bool,
+}
+
+impl ToastTimer {
+    /// Whether the timer is paused for any reason.
+    fn is_paused(&self) -> bool {
+        self.hovered || self.focused
+    }
+}
+
+/// A handle to the toasts shown by a [`ToastContainer`].
+#[derive(Clone, Copy)]
+pub struct Toasts {
+    cx: Scope,
+    toasts: RwSignal<Vec<ShownToast>>,
+    timers: StoredValue<HashMap<ToastId, ToastTimer>>,
+    scopes: StoredValue<HashMap<ToastId, ScopeDisposer>>,
+    next_id: StoredValue<usize>,
+}
+
+impl Toasts {
+    /// Creates a new, empty set of toasts.
+    fn new(cx: Scope) -> Self {
+        Self {
+            cx,
+            toasts: create_rw_signal(cx, Vec::new()),
+            timers: store_value(cx, HashMap::new()),
+            scopes: store_value(cx, HashMap::new()),
+            next_id: store_value(cx, 0),
+        }
+    }
+
+    /// Shows a toast. If an identical toast is already being shown, it is
+    /// shown again in place and its timer is restarted with the new toast's
+    /// timeout, rather than showing a duplicate.
+    pub fn push(&self, toast: Toast) -> ToastId {
+        let key = toast.dedupe_key();
+        let existing = self.toasts.with_untracked(|toasts| {
+            toasts
+                .iter()
+                .find(|shown| shown.key == key)
+                .map(|shown| (shown.id, shown.count))
+        });
+
+        if let Some((id, count)) = existing {
+            count.update(|count| *count += 1);
+            self.start_timer(id, toast.timeout);
+            return id;
+        }
+
+        let id = ToastId(self.next_id.get_value());
+        self.next_id.update_value(|next_id| *next_id += 1);
+        let timeout = toast.timeout;
+        // The count lives in its own scope so it can be disposed of along
+        // with the toast.
+        let (count, disposer) = self.cx.run_child_scope(|cx| create_rw_signal(cx, 1));
+        self.scopes.update_value(|scopes| {
+            scopes.insert(id, disposer);
+        });
+
+        self.toasts.update(|toasts| {
+            toasts.push(ShownToast {
+                id,
+                toast,
+                key,
+                count,
+            })
+        });
+        self.start_timer(id, timeout);
+
+        id
+    }
+
+    /// Shows a success toast.
+    pub fn success(&self, message: impl Into<String>) -> ToastId {
+        self.push(Toast::success(message))
+    }
+
+    /// Shows an info toast.
+    pub fn info(&self, message: impl Into<String>) -> ToastId {
+        self.push(Toast::info(message))
+    }
+
+    /// Shows a warning toast.
+    pub fn warning(&self, message: impl Into<String>) -> ToastId {
+        self.push(Toast::warning(message))
+    }
+
+    /// Shows an error toast.
+    pub fn error(&self, message: impl Into<String>) -> ToastId {
+        self.push(Toast::error(message))
+    }
+
+    /// Dismisses a toast. Does nothing if the toast has already been
+    /// dismissed.
+    pub fn dismiss(&self, id: ToastId) {
+        self.timers.update_value(|timers| {
+            timers.remove(&id);
+        });
+        self.toasts
+            .update(|toasts| toasts.retain(|shown| shown.id != id));
+
+        if let Some(disposer) = self
+            .scopes
+            .try_update_value(|scopes| scopes.remove(&id))
+            .flatten()
+        {
+            disposer.dispose();
+        }
+    }
+
+    /// Dismisses every toast.
+    pub fn clear(&self) {
+        self.timers.update_value(|timers| timers.clear());
+        self.toasts.update(|toasts| toasts.clear());
+
+        if let Some(scopes) = self.scopes.try_update_value(std::mem::take) {
+            scopes.into_values().for_each(ScopeDisposer::dispose);
+        }
+    }
+
+    /// Starts a toast's timer from the beginning. Any pending timeout is
+    /// cancelled, and the new one only runs once the toast is no longer
+    /// paused.
+    fn start_timer(&self, id: ToastId, timeout: Option<Duration>) {
+        self.timers.update_value(|timers| {
+            let timer = timers.entry(id).or_default();
+            timer.generation += 1;
+            timer.remaining = timeout;
+            timer.started = None;
+        });
+        self.resume_timer(id);
+    }
+
+    /// Sets whether a toast's timer is paused for a reason, such as while
+    /// the toast is hovered. The timer only runs while it is not paused for
+    /// any reason.
+    fn set_paused(&self, id: ToastId, pause: Pause, paused: bool) {
+        let is_paused = self.timers.try_update_value(|timers| {
+            timers.get_mut(&id).map(|timer| {
+                match pause {
+                    Pause::Hover => timer.hovered = paused,
+                    Pause::Focus => timer.focused = paused,
+                }
+
+                timer.is_paused()
+            })
+        });
+
+        match is_paused.flatten() {
+            Some(true) => self.pause_timer(id),
+            Some(false) => self.resume_timer(id),
+            None => {}
+        }
+    }
+
+    /// Stops a toast's running timer, keeping the time left.
+    fn pause_timer(&self, id: ToastId) {
+        let now = js_sys::Date::now();
+
+        self.timers.update_value(|timers| {
+            if let Some(timer) = timers.get_mut(&id) {
+                if let Some(started) = timer.started.take() {
+                    let elapsed = Duration::from_secs_f64((now - started).max(0.0) / 1000.0);
+                    timer.remaining = timer
+                        .remaining
+                        .map(|remaining| remaining.saturating_sub(elapsed));
+                    timer.generation += 1;
+                }
+            }
+        });
+    }
+
+    /// Runs a toast's timer, unless it is already running, paused, or the
+    /// toast has no timeout.
+    fn resume_timer(&self, id: ToastId) {
+        let now = js_sys::Date::now();
+        let scheduled = self.timers.with_value(|timers| {
+            timers
+                .get(&id)
+                .filter(|timer| timer.started.is_none() && !timer.is_paused())
+                .and_then(|timer| Some((timer.generation, timer.remaining?)))
+        });
+        let Some((generation, remaining)) = scheduled else {
+            return;
+        };
+
+        self.timers.update_value(|timers| {
+            if let Some(timer) = timers.get_mut(&id) {
+                timer.started = Some(now);
+            }
+        });
+
+        let toasts = *self;
+        set_timeout(
+            move || {
+                let current = toasts.timers.with_value(|timers| {
+                    timers
+                        .get(&id)
+                        .map_or(false, |timer| timer.generation == generation)
+                });
+
+                if current {
+                    toasts.dismiss(id);
+                }
+            },
+            remaining,
+        );
+    }
+}
+
+/// Gets the toasts shown by the nearest enclosing [`ToastContainer`].
+///
+/// ```
+/// # use leptonic::use_toasts;
+/// # use leptos::*;
+/// #
+/// # #[component]
+/// # fn Demo(cx: Scope) -> impl IntoView {
+/// let toasts = use_toasts(cx);
+///
+/// view! { cx,
+///     <button on:click=move |_| { toasts.success("Saved"); }>"Save"</button>
+/// }
+/// # }
+/// ```
+///
+/// This must be called within a [`ToastContainer`].
+pub fn use_toasts(cx: Scope) -> Toasts {
+    use_context::<Toasts>(cx).expect("`use_toasts` must be called within a `ToastContainer`")
+}
+
+/// Shows toast notifications pushed via [`use_toasts`] in a corner of the
+/// screen. This should be mounted once, around the whole application.
+#[component]
+pub fn ToastContainer(
+    cx: Scope,
+    /// The corner of the screen in which toasts are shown.
+    #[prop(into, optional)]
+    position: MaybeSignal<ToastPosition>,
+    /// The application.
+    children: Children,
+) -> impl IntoView {
+    let toasts = Toasts::new(cx);
+    provide_context(cx, toasts);
+
+    let container_class = move || {
+        classes!(
+            "leptonic-toast-container",
+            format!(
+                "leptonic-toast-container-{}",
+                position.with(|p| p.position_name())
+            )
+        )
+    };
+
+    let toast_view = move |cx: Scope, shown: ShownToast| {
+        let ShownToast {
+            id, toast, count, ..
+        } = shown;
+
+        let class = classes!(
+            "leptonic-toast",
+            format!("leptonic-toast-{}", toast.variant.variant_name()),
+            format!("leptonic-text-{}", toast.size.size_name())
+        );
+        let (role, live) = if toast.variant.is_urgent() {
+            ("alert", "assertive")
+        } else {
+            ("status", "polite")
+        };
+        let title = toast.title.map(|title| {
+            view! { cx, <span class="leptonic-toast-title">{title}</span> }
+        });
+        let count_badge = move || {
+            (count() > 1).then(|| {
+                view! { cx, <span class="leptonic-toast-count">{format!("\u{d7}{}", count())}</span> }
+            })
+        };
+        // The timer is paused while the toast is hovered or has focus.
+        let set_paused = move |pause: Pause, paused: bool| toasts.set_paused(id, pause, paused);
+        let actions = (!toast.actions.is_empty()).then(|| {
+            let buttons = toast
+                .actions
+                .into_iter()
+                .map(|action| {
+                    let on_click = action.on_click;
+                    view! { cx,
+                        <button
+                            class="leptonic-toast-action"
+                            type="button"
+                            on:click=move |_| {
+                                on_click();
+                                toasts.dismiss(id);
+                            }
+                        >
+                            {action.text}
+                        </button>
+                    }
+                })
+                .collect_view(cx);
+
+            view! { cx, <div class="leptonic-toast-actions">{buttons}</div> }
+        });
+
+        view! { cx,
+            <div
+                class=class
+                role=role
+                aria-live=live
+                aria-atomic="true"
+                on:mouseenter=move |_| set_paused(Pause::Hover, true)
+                on:mouseleave=move |_| set_paused(Pause::Hover, false)
+                on:focusin=move |_| set_paused(Pause::Focus, true)
+                on:focusout=move |_| set_paused(Pause::Focus, false)
+            >
+                <div class="leptonic-toast-content">
+                    {title}
+                    <span class="leptonic-toast-message">{toast.message}</span>
+                    {actions}
+                </div>
+                {count_badge}
+                <button
+                    class="leptonic-toast-close"
+                    type="button"
+                    aria-label="Dismiss"
+                    on:click=move |_| toasts.dismiss(id)
+                >
+                    <span class="leptonic-toast-close-icon"></span>
+                </button>
+            </div>
+        }
+    };
+
+    view! { cx,
+        {children(cx)}
+        <div class=container_class role="region" aria-label="Notifications">
+            <For
+                each=move || toasts.toasts.get()
+                key=|shown| shown.id
+                view=toast_view
+            />
+        </div>
+    }
+}
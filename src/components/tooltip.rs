@@ -0,0 +1,71 @@
+use super::popover::*;
+use crate::position::*;
+use crate::util::*;
+use leptos::html::{Div, ElementDescriptor};
+use leptos::*;
+use std::time::Duration;
+
+/// How long the pointer must rest on an anchor before its tooltip is shown.
+pub const DEFAULT_TOOLTIP_DELAY: Duration = Duration::from_millis(300);
+
+/// A short text label describing another element, shown while that element
+/// is hovered or focused.
+#[component]
+pub fn Tooltip<T>(
+    cx: Scope,
+    /// The element the tooltip describes.
+    anchor: NodeRef<T>,
+    /// The tooltip text.
+    #[prop(into)]
+    text: MaybeSignal<String>,
+    /// Where the tooltip is placed relative to its anchor.
+    #[prop(into, optional, default = MaybeSignal::Static(Placement::TOP))]
+    placement: MaybeSignal<Placement>,
+    /// The interaction with the anchor that shows the tooltip.
+    #[prop(into, optional, default = MaybeSignal::Static(FloatingTrigger::HoverFocus))]
+    trigger: MaybeSignal<FloatingTrigger>,
+    /// How long the pointer must rest on the anchor before the tooltip is
+    /// shown.
+    #[prop(into, optional, default = MaybeSignal::Static(DEFAULT_TOOLTIP_DELAY))]
+    delay: MaybeSignal<Duration>,
+    /// Whether to show an arrow pointing at the anchor.
+    #[prop(into, optional, default = MaybeSignal::Static(true))]
+    arrow: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    T: ElementDescriptor + Clone + 'static,
+{
+    let id = new_id();
+    let (open, set_open) = create_signal(cx, false);
+    let floating = create_node_ref::<Div>(cx);
+
+    use_floating_trigger(cx, anchor, floating, open, set_open, trigger, delay);
+
+    {
+        let id = id.clone();
+        create_effect(cx, move |_| {
+            if let Some(element) = node_ref_element(anchor) {
+                set_id_reference(&element, "aria-describedby", &id, open());
+            }
+        });
+    }
+
+    let popup = move || {
+        view! { cx,
+            <div id=id.clone() class="leptonic-tooltip" role="tooltip" node_ref=floating>
+                {text.clone()}
+                {move || arrow().then(|| view! { cx, <div class="leptonic-floating-arrow"></div> })}
+            </div>
+        }
+    };
+
+    floating_layer(
+        cx,
+        anchor,
+        floating,
+        open,
+        placement,
+        PositionOptions::default(),
+        popup,
+    );
+}
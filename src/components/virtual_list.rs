@@ -0,0 +1,196 @@
+use crate::virtualize::*;
+use leptos::html::Div;
+use leptos::*;
+use leptos_use::use_event_listener;
+use std::hash::Hash;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Gets the height of an item.
+type ItemHeightFn<T> = Rc<dyn Fn(&T) -> f64>;
+
+/// The height of the items in a virtual list, in pixels.
+pub enum ItemHeight<T> {
+    /// Every item has the same height.
+    Fixed(f64),
+    /// Each item has its own height.
+    Variable(ItemHeightFn<T>),
+}
+
+impl<T> ItemHeight<T> {
+    /// Gives each item its own height.
+    pub fn variable(height: impl Fn(&T) -> f64 + 'static) -> Self {
+        Self::Variable(Rc::new(height))
+    }
+
+    /// Lays out a list of items.
+    pub fn layout(&self, items: &[T]) -> ListLayout {
+        match self {
+            Self::Fixed(height) => ListLayout::fixed(items.len(), *height),
+            Self::Variable(height) => ListLayout::variable(items.iter().map(|item| height(item))),
+        }
+    }
+}
+
+impl<T> Clone for ItemHeight<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Fixed(height) => Self::Fixed(*height),
+            Self::Variable(height) => Self::Variable(Rc::clone(height)),
+        }
+    }
+}
+
+impl<T> From<f64> for ItemHeight<T> {
+    fn from(height: f64) -> Self {
+        Self::Fixed(height)
+    }
+}
+
+/// Tracks which items of a list are in view in a scrolling viewport.
+/// `content_offset` gets the distance from the top of the viewport's content
+/// to the first item. Returns the range of items to render, and a function
+/// that scrolls an item into view.
+pub(crate) fn use_virtual_window(
+    cx: Scope,
+    viewport: NodeRef<Div>,
+    layout: Memo<ListLayout>,
+    overscan: MaybeSignal<usize>,
+    content_offset: impl Fn() -> f64 + 'static,
+) -> (Memo<Range<usize>>, impl Fn(usize, ScrollAlign) + Clone) {
+    let (scroll_top, set_scroll_top) = create_signal(cx, 0.0);
+    let (viewport_height, set_viewport_height) = create_signal(cx, 0.0);
+    let content_offset = Rc::new(content_offset);
+
+    let measure = {
+        let content_offset = Rc::clone(&content_offset);
+        move || {
+            if let Some(viewport) = viewport.get_untracked() {
+                set_scroll_top((f64::from(viewport.scroll_top()) - content_offset()).max(0.0));
+                set_viewport_height(f64::from(viewport.client_height()));
+            }
+        }
+    };
+
+    {
+        let measure = measure.clone();
+        create_effect(cx, move |_| {
+            if viewport.get().is_some() {
+                request_animation_frame(measure.clone());
+            }
+        });
+    }
+    {
+        let measure = measure.clone();
+        _ = use_event_listener(cx, viewport, ev::scroll, move |_| measure());
+    }
+    _ = use_event_listener(cx, window(), ev::resize, move |_| measure());
+
+    let range = create_memo(cx, move |_| {
+        layout.with(|layout| layout.visible_range(scroll_top(), viewport_height(), overscan()))
+    });
+
+    let scroll_to = move |index: usize, align: ScrollAlign| {
+        if let Some(viewport) = viewport.get_untracked() {
+            let offset = layout.with_untracked(|layout| {
+                layout.scroll_offset(
+                    index,
+                    align,
+                    scroll_top.get_untracked(),
+                    viewport_height.get_untracked(),
+                )
+            });
+            viewport.set_scroll_top((offset + content_offset()).round() as i32);
+        }
+    };
+
+    (range, scroll_to)
+}
+
+/// A scrolling list that only renders the items in view, so that it stays
+/// fast with any number of items. Items must be rendered at exactly the
+/// height given for them.
+#[component]
+pub fn VirtualList<T, K, KF, VF, V>(
+    cx: Scope,
+    /// The items in the list.
+    #[prop(into)]
+    items: MaybeSignal<Vec<T>>,
+    /// The height of the items, in pixels.
+    #[prop(into)]
+    item_height: ItemHeight<T>,
+    /// Gets a key identifying an item. An item's view is kept for as long as
+    /// it stays in view.
+    key: KF,
+    /// Renders an item.
+    view: VF,
+    /// The height of the list, as a CSS length.
+    #[prop(into, optional, default = MaybeSignal::Static("400px".to_owned()))]
+    height: MaybeSignal<String>,
+    /// The number of items rendered beyond each edge of the viewport.
+    #[prop(into, optional, default = MaybeSignal::Static(3))]
+    overscan: MaybeSignal<usize>,
+    /// The index of an item to scroll to. The list scrolls to the item each
+    /// time this is set.
+    #[prop(into, optional)]
+    scroll_to: MaybeSignal<Option<usize>>,
+    /// Where to place the item scrolled to.
+    #[prop(into, optional)]
+    scroll_align: MaybeSignal<ScrollAlign>,
+    /// An accessible label for the list.
+    #[prop(into, optional)]
+    label: MaybeSignal<String>,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    K: Eq + Hash + 'static,
+    KF: Fn(&T) -> K + 'static,
+    VF: Fn(Scope, T) -> V + 'static,
+    V: IntoView,
+{
+    let items = store_value(cx, items);
+    let layout = create_memo(cx, move |_| {
+        items.with_value(|items| items.with(|items| item_height.layout(items)))
+    });
+    let viewport = create_node_ref::<Div>(cx);
+    let (range, scroll_to_index) = use_virtual_window(cx, viewport, layout, overscan, || 0.0);
+
+    create_effect(cx, move |_| {
+        if let Some(index) = scroll_to() {
+            let scroll_to_index = scroll_to_index.clone();
+            let align = scroll_align.get_untracked();
+            request_animation_frame(move || scroll_to_index(index, align));
+        }
+    });
+
+    let visible_items = move || {
+        let range = range();
+        items.with_value(|items| {
+            items.with(|items| {
+                let end = range.end.min(items.len());
+                items[range.start.min(end)..end].to_vec()
+            })
+        })
+    };
+    let space_before = move || {
+        let height = layout.with(|layout| layout.offset(range().start));
+        format!("height: {height}px;")
+    };
+    let space_after = move || {
+        let height = layout.with(|layout| layout.total_height() - layout.offset(range().end));
+        format!("height: {height}px;")
+    };
+
+    view! { cx,
+        <div
+            class="leptonic-virtual-list"
+            style=move || format!("height: {};", height())
+            aria-label=move || label.with(|label| (!label.is_empty()).then(|| label.clone()))
+            node_ref=viewport
+        >
+            <div class="leptonic-virtual-list-spacer" style=space_before aria-hidden="true"></div>
+            <For each=visible_items key=key view=view />
+            <div class="leptonic-virtual-list-spacer" style=space_after aria-hidden="true"></div>
+        </div>
+    }
+}
@@ -0,0 +1,194 @@
+use crate::color::*;
+use crate::theme::*;
+
+/// A styling theme whose colors are validated and derived at compile time.
+/// This is usually created with the [`theme!`](crate::theme!) macro, but can
+/// also be built by hand in a `static` and converted into a [`Theme`].
+///
+/// ```
+/// # use leptonic::{ConstTheme, Theme};
+/// static BRAND_THEME: ConstTheme = ConstTheme::new()
+///     .light_mode()
+///     .primary_color("#6928ff");
+///
+/// let theme = Theme::from(&BRAND_THEME);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ConstTheme {
+    /// The theme's color mode.
+    color_mode: ColorMode,
+    /// The direction in which text is laid out, if set.
+    direction: Option<TextDirection>,
+    /// The primary color.
+    primary_color: Rgba8,
+    /// The secondary color.
+    secondary_color: Rgba8,
+    /// The danger color.
+    danger_color: Rgba8,
+    /// The error text color.
+    error_color: Rgba8,
+    /// The fonts to be applied to all elements.
+    fonts: &'static [&'static str],
+    /// Whether theme changes should transition smoothly.
+    transitions: bool,
+    /// The colors derived from the theme.
+    colors: ThemeColors,
+}
+
+impl ConstTheme {
+    /// Creates the default theme.
+    pub const fn new() -> Self {
+        Self {
+            color_mode: ColorMode::Dark,
+            direction: None,
+            primary_color: DEFAULT_PRIMARY_COLOR,
+            secondary_color: DEFAULT_SECONDARY_COLOR,
+            danger_color: DEFAULT_DANGER_COLOR,
+            error_color: DEFAULT_ERROR_COLOR,
+            fonts: &[],
+            transitions: false,
+            colors: derive_colors(ColorInputs {
+                color_mode: ColorMode::Dark,
+                primary_color: DEFAULT_PRIMARY_COLOR,
+                secondary_color: DEFAULT_SECONDARY_COLOR,
+                danger_color: DEFAULT_DANGER_COLOR,
+                error_color: DEFAULT_ERROR_COLOR,
+                mid_color: DEFAULT_MID_COLOR,
+                transparent_color: DEFAULT_TRANSPARENT_COLOR,
+            }),
+        }
+    }
+
+    /// Derives the theme's colors again after a change.
+    const fn rederive(mut self) -> Self {
+        self.colors = derive_colors(ColorInputs {
+            color_mode: self.color_mode,
+            primary_color: self.primary_color,
+            secondary_color: self.secondary_color,
+            danger_color: self.danger_color,
+            error_color: self.error_color,
+            mid_color: DEFAULT_MID_COLOR,
+            transparent_color: DEFAULT_TRANSPARENT_COLOR,
+        });
+        self
+    }
+
+    /// Sets the color mode.
+    pub const fn color_mode(mut self, color_mode: ColorMode) -> Self {
+        self.color_mode = color_mode;
+        self.rederive()
+    }
+
+    /// Sets the color mode to dark mode.
+    pub const fn dark_mode(self) -> Self {
+        self.color_mode(ColorMode::Dark)
+    }
+
+    /// Sets the color mode to light mode.
+    pub const fn light_mode(self) -> Self {
+        self.color_mode(ColorMode::Light)
+    }
+
+    /// Sets the text direction.
+    pub const fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the primary color from a hex string.
+    pub const fn primary_color(mut self, primary_color: &str) -> Self {
+        self.primary_color = parse_hex_color(primary_color);
+        self.rederive()
+    }
+
+    /// Sets the secondary color from a hex string.
+    pub const fn secondary_color(mut self, secondary_color: &str) -> Self {
+        self.secondary_color = parse_hex_color(secondary_color);
+        self.rederive()
+    }
+
+    /// Sets the danger color from a hex string.
+    pub const fn danger_color(mut self, danger_color: &str) -> Self {
+        self.danger_color = parse_hex_color(danger_color);
+        self.rederive()
+    }
+
+    /// Sets the error text color from a hex string.
+    pub const fn error_color(mut self, error_color: &str) -> Self {
+        self.error_color = parse_hex_color(error_color);
+        self.rederive()
+    }
+
+    /// Sets the list of fonts.
+    pub const fn fonts(mut self, fonts: &'static [&'static str]) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// Sets whether theme changes should transition smoothly.
+    pub const fn transitions(mut self, transitions: bool) -> Self {
+        self.transitions = transitions;
+        self
+    }
+}
+
+impl Default for ConstTheme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&'static ConstTheme> for Theme {
+    fn from(value: &'static ConstTheme) -> Self {
+        Self {
+            color_mode: value.color_mode,
+            direction: value.direction,
+            primary_color: value.primary_color.into(),
+            secondary_color: value.secondary_color.into(),
+            danger_color: value.danger_color.into(),
+            error_color: value.error_color.into(),
+            fonts: value.fonts.iter().map(|&font| font.to_owned()).collect(),
+            transitions: value.transitions,
+            components: ComponentOverrides::default(),
+            precomputed_colors: Some(&value.colors),
+        }
+    }
+}
+
+/// Creates a [`Theme`](crate::Theme) whose colors are validated and derived
+/// at compile time. Each field corresponds to a [`ConstTheme`] builder
+/// method, and colors are given as hex strings. Pass the theme to
+/// [`use_theme_with`](crate::use_theme_with) so that it is the first theme
+/// applied.
+///
+/// ```
+/// # use leptonic::{theme, use_theme_with, ColorMode};
+/// # use leptos::*;
+/// #
+/// # #[component]
+/// # fn Demo(cx: Scope) -> impl IntoView {
+/// let (theme, set_theme) = use_theme_with(cx, theme! {
+///     color_mode: ColorMode::Light,
+///     primary_color: "#6928ff",
+///     fonts: &["Inter"],
+/// });
+/// #
+/// # view! { cx, }
+/// # }
+/// ```
+///
+/// An invalid color is a compile error:
+///
+/// ```compile_fail
+/// # use leptonic::theme;
+/// let theme = theme! {
+///     primary_color: "#6928fg",
+/// };
+/// ```
+#[macro_export]
+macro_rules! theme {
+    ( $( $field:ident : $value:expr ),* $(,)? ) => {{
+        static THEME: $crate::ConstTheme = $crate::ConstTheme::new() $( .$field($value) )*;
+        $crate::Theme::from(&THEME)
+    }};
+}
@@ -0,0 +1,206 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+
+/// The number of days shown in a month's calendar grid.
+pub const CALENDAR_DAYS: usize = 42;
+
+/// An inclusive range of dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    /// The first date in the range.
+    pub start: NaiveDate,
+    /// The last date in the range.
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /// Creates a range between two dates, given in either order.
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use leptonic::DateRange;
+    /// let first = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+    /// let last = NaiveDate::from_ymd_opt(2023, 7, 7).unwrap();
+    /// let range = DateRange::new(last, first);
+    ///
+    /// assert_eq!(range.start, first);
+    /// assert_eq!(range.days(), 7);
+    /// assert!(range.contains(NaiveDate::from_ymd_opt(2023, 7, 4).unwrap()));
+    /// assert_eq!(range.dates().count(), 7);
+    /// ```
+    pub fn new(date1: NaiveDate, date2: NaiveDate) -> Self {
+        Self {
+            start: date1.min(date2),
+            end: date1.max(date2),
+        }
+    }
+
+    /// Determines whether the range contains a date.
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Gets the number of days in the range.
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+
+    /// Iterates over every date in the range, in order.
+    pub fn dates(&self) -> impl Iterator<Item = NaiveDate> {
+        let end = self.end;
+        self.start.iter_days().take_while(move |&date| date <= end)
+    }
+}
+
+/// Gets the first day of the month containing the given date.
+pub fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap()
+}
+
+/// Gets the last day of the month containing the given date.
+pub fn month_end(date: NaiveDate) -> NaiveDate {
+    month_start(date)
+        .checked_add_months(Months::new(1))
+        .and_then(|next_month| next_month.pred_opt())
+        .unwrap_or(NaiveDate::MAX)
+}
+
+/// Adds a number of months to a date, which may be negative. The day is
+/// clamped to the end of the resulting month, so adding one month to January
+/// 31st gives the last day of February.
+pub fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let result = if months < 0 {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))
+    } else {
+        date.checked_add_months(Months::new(months as u32))
+    };
+
+    result.unwrap_or(date)
+}
+
+/// Gets the dates shown in a month's calendar grid. The grid always spans six
+/// full weeks starting on `first_day_of_week`, filled in with days from the
+/// neighbouring months.
+///
+/// ```
+/// # use chrono::{NaiveDate, Weekday};
+/// # use leptonic::calendar_days;
+/// let february = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+/// let days = calendar_days(february, Weekday::Mon);
+///
+/// assert_eq!(days.len(), 42);
+/// assert_eq!(days[0], NaiveDate::from_ymd_opt(2024, 1, 29).unwrap());
+/// assert_eq!(days[3], february);
+/// ```
+pub fn calendar_days(month: NaiveDate, first_day_of_week: Weekday) -> Vec<NaiveDate> {
+    let start = month_start(month);
+    let offset = start.weekday().days_since(first_day_of_week);
+    let first = start - Duration::days(offset as i64);
+
+    first.iter_days().take(CALENDAR_DAYS).collect()
+}
+
+/// Gets the days of the week, in order, starting on `first_day_of_week`.
+pub fn week_days(first_day_of_week: Weekday) -> [Weekday; 7] {
+    let mut days = [first_day_of_week; 7];
+    for index in 1..7 {
+        days[index] = days[index - 1].succ();
+    }
+    days
+}
+
+/// Determines whether a date falls within optional inclusive bounds.
+pub fn date_in_range(date: NaiveDate, min: Option<NaiveDate>, max: Option<NaiveDate>) -> bool {
+    min.map_or(true, |min| date >= min) && max.map_or(true, |max| date <= max)
+}
+
+/// Clamps a date to optional inclusive bounds.
+pub fn clamp_date(date: NaiveDate, min: Option<NaiveDate>, max: Option<NaiveDate>) -> NaiveDate {
+    match (min, max) {
+        (Some(min), _) if date < min => min,
+        (_, Some(max)) if date > max => max,
+        _ => date,
+    }
+}
+
+/// Gets the date that focus moves to when a key is pressed in a calendar
+/// grid, if the key navigates. Left and right arrows are swapped in
+/// right-to-left layouts.
+pub(crate) fn calendar_key_target(
+    key: &str,
+    shift: bool,
+    rtl: bool,
+    date: NaiveDate,
+    first_day_of_week: Weekday,
+) -> Option<NaiveDate> {
+    let step = if rtl { -1 } else { 1 };
+    let weekday_offset = date.weekday().days_since(first_day_of_week) as i64;
+
+    match key {
+        "ArrowLeft" => Some(date - Duration::days(step)),
+        "ArrowRight" => Some(date + Duration::days(step)),
+        "ArrowUp" => Some(date - Duration::days(7)),
+        "ArrowDown" => Some(date + Duration::days(7)),
+        "Home" => Some(date - Duration::days(weekday_offset)),
+        "End" => Some(date + Duration::days(6 - weekday_offset)),
+        "PageUp" if shift => Some(add_months(date, -12)),
+        "PageDown" if shift => Some(add_months(date, 12)),
+        "PageUp" => Some(add_months(date, -1)),
+        "PageDown" => Some(add_months(date, 1)),
+        _ => None,
+    }
+}
+
+/// Gets the full name of a date's month.
+pub(crate) fn month_name(date: NaiveDate) -> &'static str {
+    const MONTH_NAMES: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+
+    MONTH_NAMES[date.month0() as usize]
+}
+
+/// Gets the two letter abbreviation of a day of the week.
+pub(crate) fn weekday_abbreviation(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Mo",
+        Weekday::Tue => "Tu",
+        Weekday::Wed => "We",
+        Weekday::Thu => "Th",
+        Weekday::Fri => "Fr",
+        Weekday::Sat => "Sa",
+        Weekday::Sun => "Su",
+    }
+}
+
+/// Gets the accessible label of a calendar day, such as `Friday, July 14,
+/// 2023`.
+pub(crate) fn day_label(date: NaiveDate) -> String {
+    date.format("%A, %B %-d, %Y").to_string()
+}
+
+/// Parses a date in the given format, ignoring surrounding whitespace.
+pub(crate) fn parse_date(text: &str, format: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim(), format).ok()
+}
+
+/// Gets the current local date.
+pub(crate) fn today() -> NaiveDate {
+    let now = js_sys::Date::new_0();
+    NaiveDate::from_ymd_opt(
+        now.get_full_year() as i32,
+        now.get_month() + 1,
+        now.get_date(),
+    )
+    .unwrap_or_default()
+}
@@ -0,0 +1,179 @@
+use crate::theme::*;
+
+/// Escapes a string for use as a JSON string literal, including quotes.
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for chr in value.chars() {
+        match chr {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            chr if chr.is_control() => {
+                escaped.push_str(&format!("\\u{:04x}", chr as u32));
+            }
+            chr => escaped.push(chr),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+/// Formats a list of strings as a JSON array.
+fn json_string_array(values: &[String]) -> String {
+    let values = values
+        .iter()
+        .map(|value| json_string(value))
+        .collect::<Vec<_>>();
+    format!("[{}]", values.join(", "))
+}
+
+/// Splits a CSS font family list into individual, unquoted families.
+fn font_families(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|family| family.trim().trim_matches('"').to_owned())
+        .filter(|family| !family.is_empty())
+        .collect()
+}
+
+/// Shortens a variable name for use as a Tailwind color name by removing its
+/// `color` segments, e.g. `primary-color-hover` becomes `primary-hover`.
+fn tailwind_color_name(name: &str) -> String {
+    name.split('-')
+        .filter(|segment| *segment != "color")
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Gets the design token type corresponding to a kind of theme variable, if
+/// the design tokens format has one.
+fn design_token_type(kind: ThemeVariableKind) -> Option<&'static str> {
+    match kind {
+        ThemeVariableKind::Color => Some("color"),
+        ThemeVariableKind::FontFamily => Some("fontFamily"),
+        ThemeVariableKind::Dimension => Some("dimension"),
+        ThemeVariableKind::Filter | ThemeVariableKind::Image => None,
+    }
+}
+
+/// Whether a CSS value is a valid design token dimension: a single number of
+/// pixels or rems.
+fn is_token_dimension(value: &str) -> bool {
+    value
+        .strip_suffix("px")
+        .or_else(|| value.strip_suffix("rem"))
+        .and_then(|number| number.parse::<f64>().ok())
+        .map_or(false, f64::is_finite)
+}
+
+impl Theme {
+    /// Exports the theme's colors and fonts as a Tailwind CSS config, for use
+    /// as a preset. Colors are grouped under `leptonic`, with their `color`
+    /// segments removed, so `--leptonic-primary-color-hover` becomes the
+    /// `bg-leptonic-primary-hover` utility.
+    pub fn to_tailwind_config(&self) -> String {
+        let mut colors = String::new();
+        let mut fonts = Vec::new();
+
+        for var in self.css_variables() {
+            match var.kind {
+                ThemeVariableKind::Color => colors.push_str(&format!(
+                    "          {}: {},\n",
+                    json_string(&tailwind_color_name(&var.name)),
+                    json_string(&var.value)
+                )),
+                ThemeVariableKind::FontFamily => fonts = font_families(&var.value),
+                _ => {}
+            }
+        }
+
+        let mut config = String::new();
+        config.push_str("module.exports = {\n");
+        config.push_str("  theme: {\n");
+        config.push_str("    extend: {\n");
+        config.push_str("      colors: {\n");
+        config.push_str("        leptonic: {\n");
+        config.push_str(&colors);
+        config.push_str("        },\n");
+        config.push_str("      },\n");
+        config.push_str("      fontFamily: {\n");
+        config.push_str(&format!(
+            "        leptonic: {},\n",
+            json_string_array(&fonts)
+        ));
+        config.push_str("      },\n");
+        config.push_str("    },\n");
+        config.push_str("  },\n");
+        config.push_str("};\n");
+        config
+    }
+
+    /// Exports the theme as a design tokens JSON file, in the W3C design
+    /// tokens format understood by Style Dictionary. Tokens are grouped under
+    /// `leptonic` and named after their CSS variables. Filters and images have
+    /// no design token type, so they are omitted. Lengths that are not a
+    /// single number of pixels or rems, such as padding shorthands, are not
+    /// valid dimensions, so they are exported without a type.
+    ///
+    /// ```
+    /// # use leptonic::{ButtonOverrides, Theme};
+    /// let tokens = Theme::default()
+    ///     .button_overrides(
+    ///         ButtonOverrides::default()
+    ///             .border_radius("4px")
+    ///             .padding("6px 12px"),
+    ///     )
+    ///     .to_design_tokens();
+    ///
+    /// assert!(tokens.contains(
+    ///     r#""button-border-radius": { "$type": "dimension", "$value": "4px" }"#
+    /// ));
+    /// assert!(tokens.contains(r#""button-padding": { "$value": "6px 12px" }"#));
+    /// ```
+    pub fn to_design_tokens(&self) -> String {
+        let tokens = self
+            .css_variables()
+            .into_iter()
+            .filter_map(|var| {
+                let token_type = design_token_type(var.kind)?;
+                let token_type = (var.kind != ThemeVariableKind::Dimension
+                    || is_token_dimension(&var.value))
+                .then(|| format!("\"$type\": {}, ", json_string(token_type)));
+                let value = match var.kind {
+                    ThemeVariableKind::FontFamily => json_string_array(&font_families(&var.value)),
+                    _ => json_string(&var.value),
+                };
+
+                Some(format!(
+                    "    {}: {{ {}\"$value\": {} }}",
+                    json_string(&var.name),
+                    token_type.unwrap_or_default(),
+                    value
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        format!("{{\n  \"leptonic\": {{\n{}\n  }}\n}}\n", tokens.join(",\n"))
+    }
+
+    /// Exports the theme as a list of SCSS variables, named after their CSS
+    /// variables.
+    ///
+    /// ```
+    /// # use leptonic::Theme;
+    /// let scss = Theme::default().primary_color((105, 40, 255)).to_scss();
+    ///
+    /// assert!(scss.contains("$leptonic-primary-color: #6928ff;\n"));
+    /// ```
+    pub fn to_scss(&self) -> String {
+        self.css_variables()
+            .into_iter()
+            .map(|var| format!("$leptonic-{}: {};\n", var.name, var.value))
+            .collect()
+    }
+}
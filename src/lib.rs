@@ -1,8 +1,21 @@
 mod classes;
+mod color;
 mod components;
+mod const_theme;
+mod date;
+mod export;
 mod number;
+mod position;
+mod table;
 mod theme;
 mod util;
+mod virtualize;
 
+pub use color::*;
 pub use components::*;
+pub use const_theme::*;
+pub use date::*;
+pub use position::*;
+pub use table::*;
 pub use theme::*;
+pub use virtualize::*;
@@ -24,6 +24,8 @@ pub trait Number:
     const DECIMAL: bool;
 
     fn as_f64(self) -> f64;
+
+    fn from_f64(value: f64) -> Self;
 }
 
 /// Implements the `Number` trait for integer primitives.
@@ -39,6 +41,10 @@ macro_rules! impl_number_int {
                 fn as_f64(self) -> f64 {
                     self as f64
                 }
+
+                fn from_f64(value: f64) -> Self {
+                    value as Self
+                }
             }
         )*
     };
@@ -57,6 +63,10 @@ macro_rules! impl_number_float {
                 fn as_f64(self) -> f64 {
                     self as f64
                 }
+
+                fn from_f64(value: f64) -> Self {
+                    value as Self
+                }
             }
         )*
     };
@@ -0,0 +1,471 @@
+/// A side of an element.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Side {
+    /// The top side.
+    Top,
+    /// The bottom side.
+    #[default]
+    Bottom,
+    /// The left side.
+    Left,
+    /// The right side.
+    Right,
+}
+
+impl Side {
+    /// Gets the name of the side.
+    pub fn side_name(&self) -> &'static str {
+        match *self {
+            Self::Top => "top",
+            Self::Bottom => "bottom",
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+
+    /// Gets the opposite side.
+    pub fn opposite(&self) -> Self {
+        match *self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// Whether this is the top or bottom side.
+    pub fn is_vertical(&self) -> bool {
+        matches!(*self, Self::Top | Self::Bottom)
+    }
+}
+
+/// The alignment of a floating element along the side of its anchor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alignment {
+    /// Aligned with the anchor's left or top edge.
+    Start,
+    /// Centered on the anchor.
+    #[default]
+    Center,
+    /// Aligned with the anchor's right or bottom edge.
+    End,
+}
+
+impl Alignment {
+    /// Gets the name of the alignment.
+    pub fn alignment_name(&self) -> &'static str {
+        match *self {
+            Self::Start => "start",
+            Self::Center => "center",
+            Self::End => "end",
+        }
+    }
+
+    /// Gets the mirrored alignment, swapping start and end.
+    pub fn mirrored(&self) -> Self {
+        match *self {
+            Self::Start => Self::End,
+            Self::Center => Self::Center,
+            Self::End => Self::Start,
+        }
+    }
+}
+
+/// Where a floating element is placed relative to its anchor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Placement {
+    /// The side of the anchor the floating element is placed on.
+    pub side: Side,
+    /// The alignment along that side.
+    pub alignment: Alignment,
+}
+
+impl Placement {
+    /// Above the anchor, centered.
+    pub const TOP: Self = Self::new(Side::Top, Alignment::Center);
+    /// Above the anchor, aligned with its left edge.
+    pub const TOP_START: Self = Self::new(Side::Top, Alignment::Start);
+    /// Above the anchor, aligned with its right edge.
+    pub const TOP_END: Self = Self::new(Side::Top, Alignment::End);
+    /// Below the anchor, centered.
+    pub const BOTTOM: Self = Self::new(Side::Bottom, Alignment::Center);
+    /// Below the anchor, aligned with its left edge.
+    pub const BOTTOM_START: Self = Self::new(Side::Bottom, Alignment::Start);
+    /// Below the anchor, aligned with its right edge.
+    pub const BOTTOM_END: Self = Self::new(Side::Bottom, Alignment::End);
+    /// Left of the anchor, centered.
+    pub const LEFT: Self = Self::new(Side::Left, Alignment::Center);
+    /// Left of the anchor, aligned with its top edge.
+    pub const LEFT_START: Self = Self::new(Side::Left, Alignment::Start);
+    /// Left of the anchor, aligned with its bottom edge.
+    pub const LEFT_END: Self = Self::new(Side::Left, Alignment::End);
+    /// Right of the anchor, centered.
+    pub const RIGHT: Self = Self::new(Side::Right, Alignment::Center);
+    /// Right of the anchor, aligned with its top edge.
+    pub const RIGHT_START: Self = Self::new(Side::Right, Alignment::Start);
+    /// Right of the anchor, aligned with its bottom edge.
+    pub const RIGHT_END: Self = Self::new(Side::Right, Alignment::End);
+
+    /// Creates a new placement.
+    pub const fn new(side: Side, alignment: Alignment) -> Self {
+        Self { side, alignment }
+    }
+
+    /// Gets the name of the placement, such as `top` or `bottom-start`.
+    pub fn placement_name(&self) -> String {
+        match self.alignment {
+            Alignment::Center => self.side.side_name().to_owned(),
+            alignment => format!("{}-{}", self.side.side_name(), alignment.alignment_name()),
+        }
+    }
+}
+
+/// A rectangle, in pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rect {
+    /// The position of the left edge.
+    pub x: f64,
+    /// The position of the top edge.
+    pub y: f64,
+    /// The width.
+    pub width: f64,
+    /// The height.
+    pub height: f64,
+}
+
+impl Rect {
+    /// Creates a new rectangle.
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Gets the position of the right edge.
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    /// Gets the position of the bottom edge.
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+/// The size of a rectangle, in pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Size {
+    /// The width.
+    pub width: f64,
+    /// The height.
+    pub height: f64,
+}
+
+impl Size {
+    /// Creates a new size.
+    pub fn new(width: f64, height: f64) -> Self {
+        Self { width, height }
+    }
+}
+
+/// Options for positioning a floating element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionOptions {
+    /// The gap between the anchor and the floating element.
+    pub offset: f64,
+    /// The minimum distance kept between the floating element and the edges
+    /// of the viewport.
+    pub padding: f64,
+    /// The minimum distance kept between an arrow and the ends of the
+    /// floating element.
+    pub arrow_padding: f64,
+    /// Whether to move the floating element to the opposite side of the
+    /// anchor when there is not enough room on the preferred side.
+    pub flip: bool,
+    /// Whether to slide the floating element along the side of the anchor to
+    /// keep it within the viewport.
+    pub shift: bool,
+}
+
+impl Default for PositionOptions {
+    fn default() -> Self {
+        Self {
+            offset: 8.0,
+            padding: 8.0,
+            arrow_padding: 8.0,
+            flip: true,
+            shift: true,
+        }
+    }
+}
+
+impl PositionOptions {
+    /// Sets the gap between the anchor and the floating element.
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the minimum distance from the edges of the viewport.
+    pub fn padding(mut self, padding: f64) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the minimum distance between an arrow and the ends of the
+    /// floating element.
+    pub fn arrow_padding(mut self, arrow_padding: f64) -> Self {
+        self.arrow_padding = arrow_padding;
+        self
+    }
+
+    /// Sets whether the floating element may flip to the opposite side.
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Sets whether the floating element may shift to stay in the viewport.
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+}
+
+/// The computed position of a floating element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    /// The position of the floating element's left edge.
+    pub x: f64,
+    /// The position of the floating element's top edge.
+    pub y: f64,
+    /// The placement used, after any flipping.
+    pub placement: Placement,
+    /// The distance from the floating element's left edge, or its top edge
+    /// when placed to the left or right, at which an arrow should point to
+    /// the center of the anchor.
+    pub arrow_offset: f64,
+}
+
+/// Gets the position of a floating element placed on the given side of an
+/// anchor, without regard for the viewport.
+fn place(anchor: Rect, floating: Size, placement: Placement, offset: f64) -> (f64, f64) {
+    let align = |start: f64, anchor_length: f64, floating_length: f64| match placement.alignment {
+        Alignment::Start => start,
+        Alignment::Center => start + (anchor_length - floating_length) / 2.0,
+        Alignment::End => start + anchor_length - floating_length,
+    };
+
+    match placement.side {
+        Side::Top => (
+            align(anchor.x, anchor.width, floating.width),
+            anchor.y - floating.height - offset,
+        ),
+        Side::Bottom => (
+            align(anchor.x, anchor.width, floating.width),
+            anchor.bottom() + offset,
+        ),
+        Side::Left => (
+            anchor.x - floating.width - offset,
+            align(anchor.y, anchor.height, floating.height),
+        ),
+        Side::Right => (
+            anchor.right() + offset,
+            align(anchor.y, anchor.height, floating.height),
+        ),
+    }
+}
+
+/// Gets how far a floating element at the given position overflows the
+/// viewport on a side.
+fn overflow(x: f64, y: f64, floating: Size, viewport: Rect, padding: f64, side: Side) -> f64 {
+    match side {
+        Side::Top => viewport.y + padding - y,
+        Side::Bottom => y + floating.height - (viewport.bottom() - padding),
+        Side::Left => viewport.x + padding - x,
+        Side::Right => x + floating.width - (viewport.right() - padding),
+    }
+}
+
+/// Clamps a position to a range, preferring the start of the range when the
+/// range is empty.
+fn clamp_start(value: f64, min: f64, max: f64) -> f64 {
+    value.min(max).max(min)
+}
+
+/// Computes where to place a floating element, such as a tooltip or a
+/// popup, next to an anchor element. All rectangles must be in the same
+/// coordinate space, usually the viewport's.
+///
+/// When there is not enough room on the preferred side, the floating element
+/// flips to the opposite side if that overflows less. It then shifts along
+/// the side of the anchor to stay within the viewport.
+///
+/// ```
+/// # use leptonic::{compute_position, Placement, PositionOptions, Rect, Side, Size};
+/// let viewport = Rect::new(0.0, 0.0, 800.0, 600.0);
+/// let options = PositionOptions::default().offset(4.0).padding(0.0);
+///
+/// // There is room above the anchor, so the tooltip is centered above it.
+/// let anchor = Rect::new(100.0, 100.0, 50.0, 20.0);
+/// let position = compute_position(anchor, Size::new(80.0, 30.0), viewport, Placement::TOP, options);
+/// assert_eq!((position.x, position.y), (85.0, 66.0));
+/// assert_eq!(position.arrow_offset, 40.0);
+///
+/// // Near the top of the viewport, it flips below the anchor.
+/// let anchor = Rect::new(100.0, 10.0, 50.0, 20.0);
+/// let position = compute_position(anchor, Size::new(80.0, 30.0), viewport, Placement::TOP, options);
+/// assert_eq!(position.placement.side, Side::Bottom);
+/// assert_eq!(position.y, 34.0);
+///
+/// // Near the right edge, it shifts left, keeping the arrow on the anchor.
+/// let anchor = Rect::new(770.0, 100.0, 20.0, 20.0);
+/// let position = compute_position(anchor, Size::new(80.0, 30.0), viewport, Placement::TOP, options);
+/// assert_eq!(position.x, 720.0);
+/// assert_eq!(position.arrow_offset, 60.0);
+/// ```
+///
+/// Elements can be placed on any side, aligned with either end of the
+/// anchor. The arrow offset runs along the side the element is placed on.
+///
+/// ```
+/// # use leptonic::{compute_position, Placement, PositionOptions, Rect, Size};
+/// # let viewport = Rect::new(0.0, 0.0, 800.0, 600.0);
+/// # let options = PositionOptions::default().offset(4.0).padding(0.0);
+/// let anchor = Rect::new(100.0, 100.0, 50.0, 20.0);
+/// let size = Size::new(80.0, 30.0);
+///
+/// let position = compute_position(anchor, size, viewport, Placement::RIGHT_START, options);
+/// assert_eq!((position.x, position.y), (154.0, 100.0));
+/// assert_eq!(position.arrow_offset, 10.0);
+///
+/// let position = compute_position(anchor, size, viewport, Placement::LEFT_END, options);
+/// assert_eq!((position.x, position.y), (16.0, 90.0));
+/// assert_eq!(position.arrow_offset, 20.0);
+///
+/// let position = compute_position(anchor, size, viewport, Placement::BOTTOM_END, options);
+/// assert_eq!((position.x, position.y), (70.0, 124.0));
+/// assert_eq!(position.arrow_offset, 55.0);
+/// ```
+///
+/// Flipping and shifting can be turned off, and an element that overflows
+/// on both sides stays on the side that overflows less.
+///
+/// ```
+/// # use leptonic::{compute_position, Placement, PositionOptions, Rect, Side, Size};
+/// # let viewport = Rect::new(0.0, 0.0, 800.0, 600.0);
+/// # let options = PositionOptions::default().offset(4.0).padding(0.0);
+/// let size = Size::new(80.0, 30.0);
+///
+/// let anchor = Rect::new(100.0, 10.0, 50.0, 20.0);
+/// let position = compute_position(anchor, size, viewport, Placement::TOP, options.flip(false));
+/// assert_eq!(position.placement.side, Side::Top);
+/// assert_eq!(position.y, -24.0);
+///
+/// let anchor = Rect::new(770.0, 100.0, 20.0, 20.0);
+/// let position = compute_position(anchor, size, viewport, Placement::TOP, options.shift(false));
+/// assert_eq!(position.x, 740.0);
+///
+/// let anchor = Rect::new(100.0, 260.0, 50.0, 100.0);
+/// let tall = Size::new(80.0, 300.0);
+/// let position = compute_position(anchor, tall, viewport, Placement::TOP, options);
+/// assert_eq!(position.placement.side, Side::Top);
+/// assert_eq!(position.y, -44.0);
+/// ```
+///
+/// Padding keeps the element away from the edges of the viewport, and the
+/// arrow stays `arrow_padding` away from the element's ends. An element too
+/// small for that has its arrow centered.
+///
+/// ```
+/// # use leptonic::{compute_position, Placement, PositionOptions, Rect, Side, Size};
+/// # let viewport = Rect::new(0.0, 0.0, 800.0, 600.0);
+/// let options = PositionOptions::default().offset(4.0).padding(8.0);
+/// let size = Size::new(80.0, 30.0);
+///
+/// // Overflowing the padding is enough to flip.
+/// let anchor = Rect::new(100.0, 40.0, 50.0, 20.0);
+/// let position = compute_position(anchor, size, viewport, Placement::TOP, options);
+/// assert_eq!(position.placement.side, Side::Bottom);
+///
+/// let anchor = Rect::new(2.0, 100.0, 20.0, 20.0);
+/// let position = compute_position(anchor, size, viewport, Placement::TOP, options);
+/// assert_eq!(position.x, 8.0);
+/// assert_eq!(position.arrow_offset, 8.0);
+///
+/// let anchor = Rect::new(100.0, 100.0, 50.0, 20.0);
+/// let position = compute_position(anchor, Size::new(12.0, 30.0), viewport, Placement::TOP, options);
+/// assert_eq!(position.x, 119.0);
+/// assert_eq!(position.arrow_offset, 6.0);
+/// ```
+pub fn compute_position(
+    anchor: Rect,
+    floating: Size,
+    viewport: Rect,
+    placement: Placement,
+    options: PositionOptions,
+) -> Position {
+    let (mut x, mut y) = place(anchor, floating, placement, options.offset);
+    let mut placement = placement;
+
+    if options.flip {
+        let current = overflow(x, y, floating, viewport, options.padding, placement.side);
+
+        if current > 0.0 {
+            let flipped = Placement::new(placement.side.opposite(), placement.alignment);
+            let (flipped_x, flipped_y) = place(anchor, floating, flipped, options.offset);
+            let flipped_overflow = overflow(
+                flipped_x,
+                flipped_y,
+                floating,
+                viewport,
+                options.padding,
+                flipped.side,
+            );
+
+            if flipped_overflow < current {
+                (x, y, placement) = (flipped_x, flipped_y, flipped);
+            }
+        }
+    }
+
+    if options.shift {
+        if placement.side.is_vertical() {
+            x = clamp_start(
+                x,
+                viewport.x + options.padding,
+                viewport.right() - options.padding - floating.width,
+            );
+        } else {
+            y = clamp_start(
+                y,
+                viewport.y + options.padding,
+                viewport.bottom() - options.padding - floating.height,
+            );
+        }
+    }
+
+    let (anchor_center, floating_start, floating_length) = if placement.side.is_vertical() {
+        (anchor.x + anchor.width / 2.0, x, floating.width)
+    } else {
+        (anchor.y + anchor.height / 2.0, y, floating.height)
+    };
+    let arrow_offset = if floating_length > options.arrow_padding * 2.0 {
+        (anchor_center - floating_start).clamp(
+            options.arrow_padding,
+            floating_length - options.arrow_padding,
+        )
+    } else {
+        floating_length / 2.0
+    };
+
+    Position {
+        x,
+        y,
+        placement,
+        arrow_offset,
+    }
+}
@@ -0,0 +1,360 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// The direction a table column is sorted in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Smallest values first.
+    #[default]
+    Ascending,
+    /// Largest values first.
+    Descending,
+}
+
+impl SortDirection {
+    /// Gets the name of the direction. This is also the value of the
+    /// `aria-sort` attribute for a column sorted in this direction.
+    pub fn direction_name(&self) -> &'static str {
+        match *self {
+            Self::Ascending => "ascending",
+            Self::Descending => "descending",
+        }
+    }
+
+    /// Applies the direction to an ascending ordering.
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match *self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
+        }
+    }
+}
+
+/// A column a table is sorted by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSort {
+    /// The ID of the column.
+    pub column: String,
+    /// The direction the column is sorted in.
+    pub direction: SortDirection,
+}
+
+impl ColumnSort {
+    /// Creates a new column sort.
+    pub fn new(column: impl Into<String>, direction: SortDirection) -> Self {
+        Self {
+            column: column.into(),
+            direction,
+        }
+    }
+}
+
+/// A filter on the values of a table column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnFilter {
+    /// The ID of the column.
+    pub column: String,
+    /// The text to filter by.
+    pub query: String,
+}
+
+impl ColumnFilter {
+    /// Creates a new column filter.
+    pub fn new(column: impl Into<String>, query: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            query: query.into(),
+        }
+    }
+}
+
+/// Whether a text matches a filter query, ignoring case and surrounding
+/// whitespace in the query. An empty query matches everything.
+///
+/// ```
+/// # use leptonic::text_matches;
+/// assert!(text_matches("Ada Lovelace", "love"));
+/// assert!(text_matches("Ada Lovelace", " ADA "));
+/// assert!(text_matches("Ada Lovelace", ""));
+/// assert!(!text_matches("Ada Lovelace", "grace"));
+/// ```
+pub fn text_matches(text: &str, query: &str) -> bool {
+    let query = query.trim();
+    query.is_empty() || text.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// Gets the number of pages needed to show a number of items. There is
+/// always at least one page, even when there are no items.
+///
+/// ```
+/// # use leptonic::page_count;
+/// assert_eq!(page_count(0, 10), 1);
+/// assert_eq!(page_count(10, 10), 1);
+/// assert_eq!(page_count(11, 10), 2);
+/// ```
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    if page_size == 0 {
+        1
+    } else {
+        total.div_ceil(page_size).max(1)
+    }
+}
+
+/// Gets the range of items shown on a page, clamping the page to the last
+/// one.
+///
+/// ```
+/// # use leptonic::page_range;
+/// assert_eq!(page_range(25, 0, 10), 0..10);
+/// assert_eq!(page_range(25, 2, 10), 20..25);
+/// assert_eq!(page_range(25, 7, 10), 20..25);
+/// assert_eq!(page_range(0, 0, 10), 0..0);
+/// ```
+pub fn page_range(total: usize, page: usize, page_size: usize) -> Range<usize> {
+    if page_size == 0 {
+        return 0..total;
+    }
+
+    let page = page.min(page_count(total, page_size) - 1);
+    let start = page * page_size;
+    start..(start + page_size).min(total)
+}
+
+/// An entry in a list of page links.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageItem {
+    /// A link to a page, starting at zero.
+    Page(usize),
+    /// A gap standing in for two or more pages left out.
+    Ellipsis,
+}
+
+/// Gets the page links to show for a page, with the first and last pages,
+/// the page itself and `siblings` pages on either side of it. Other pages
+/// are left out. The number of links stays the same while moving between
+/// pages, so that the links do not jump around.
+///
+/// ```
+/// # use leptonic::{page_items, PageItem::*};
+/// assert_eq!(page_items(1, 4, 1), vec![Page(0), Page(1), Page(2), Page(3)]);
+/// assert_eq!(
+///     page_items(0, 10, 1),
+///     vec![Page(0), Page(1), Page(2), Page(3), Page(4), Ellipsis, Page(9)]
+/// );
+/// assert_eq!(
+///     page_items(5, 10, 1),
+///     vec![Page(0), Ellipsis, Page(4), Page(5), Page(6), Ellipsis, Page(9)]
+/// );
+/// assert_eq!(
+///     page_items(8, 10, 1),
+///     vec![Page(0), Ellipsis, Page(5), Page(6), Page(7), Page(8), Page(9)]
+/// );
+/// ```
+pub fn page_items(page: usize, page_count: usize, siblings: usize) -> Vec<PageItem> {
+    // The first and last pages, the current page, its siblings, and a gap on
+    // either side.
+    let slots = 2 * siblings + 5;
+
+    if page_count <= slots {
+        return (0..page_count).map(PageItem::Page).collect();
+    }
+
+    let last = page_count - 1;
+    let page = page.min(last);
+    let start = page.saturating_sub(siblings).max(1);
+    let end = (page + siblings).min(last - 1);
+    // Pages shown next to the first or last page when only one gap is shown.
+    let edge = slots - 2;
+
+    let mut items = vec![PageItem::Page(0)];
+
+    match (start > 2, end + 2 < last) {
+        (false, _) => {
+            items.extend((1..edge).map(PageItem::Page));
+            items.push(PageItem::Ellipsis);
+        }
+        (true, false) => {
+            items.push(PageItem::Ellipsis);
+            items.extend((page_count - edge..last).map(PageItem::Page));
+        }
+        (true, true) => {
+            items.push(PageItem::Ellipsis);
+            items.extend((start..=end).map(PageItem::Page));
+            items.push(PageItem::Ellipsis);
+        }
+    }
+
+    items.push(PageItem::Page(last));
+    items
+}
+
+/// The sorting, filtering and pagination applied to the rows of a table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableQuery {
+    /// The columns the rows are sorted by, in order of priority.
+    pub sorts: Vec<ColumnSort>,
+    /// The filters a row must match to be shown.
+    pub filters: Vec<ColumnFilter>,
+    /// The number of rows on each page, or `None` to show every row.
+    pub page_size: Option<usize>,
+}
+
+/// The rows shown by a table after a query is applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableRows {
+    /// The indices of the rows on the current page, in display order.
+    pub indices: Vec<usize>,
+    /// The number of rows matching the filters, across all pages.
+    pub total: usize,
+    /// The page shown, clamped to the last page.
+    pub page: usize,
+    /// The number of pages.
+    pub page_count: usize,
+}
+
+impl TableQuery {
+    /// Sets the number of rows on each page.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Gets the direction a column is sorted in, and its priority among the
+    /// sorted columns.
+    pub fn sort_of(&self, column: &str) -> Option<(usize, SortDirection)> {
+        self.sorts
+            .iter()
+            .position(|sort| sort.column == column)
+            .map(|index| (index, self.sorts[index].direction))
+    }
+
+    /// Cycles a column from unsorted to ascending, descending, and back to
+    /// unsorted. Without `multi`, every other column stops being sorted;
+    /// with it, newly sorted columns are added after the existing ones.
+    ///
+    /// ```
+    /// # use leptonic::{SortDirection, TableQuery};
+    /// let mut query = TableQuery::default();
+    /// query.toggle_sort("name", false);
+    /// assert_eq!(query.sort_of("name"), Some((0, SortDirection::Ascending)));
+    ///
+    /// query.toggle_sort("age", true);
+    /// assert_eq!(query.sort_of("age"), Some((1, SortDirection::Ascending)));
+    ///
+    /// query.toggle_sort("name", true);
+    /// assert_eq!(query.sort_of("name"), Some((0, SortDirection::Descending)));
+    ///
+    /// query.toggle_sort("name", true);
+    /// assert_eq!(query.sort_of("name"), None);
+    /// assert_eq!(query.sort_of("age"), Some((0, SortDirection::Ascending)));
+    ///
+    /// query.toggle_sort("name", false);
+    /// assert_eq!(query.sort_of("age"), None);
+    /// ```
+    pub fn toggle_sort(&mut self, column: &str, multi: bool) {
+        let current = self.sort_of(column);
+
+        if !multi {
+            self.sorts.retain(|sort| sort.column == column);
+        }
+
+        match current {
+            None => self
+                .sorts
+                .push(ColumnSort::new(column, SortDirection::Ascending)),
+            Some((_, SortDirection::Ascending)) => {
+                for sort in self.sorts.iter_mut().filter(|sort| sort.column == column) {
+                    sort.direction = SortDirection::Descending;
+                }
+            }
+            Some((_, SortDirection::Descending)) => self.sorts.retain(|sort| sort.column != column),
+        }
+    }
+
+    /// Gets the filter query of a column.
+    pub fn filter_of(&self, column: &str) -> &str {
+        self.filters
+            .iter()
+            .find(|filter| filter.column == column)
+            .map_or("", |filter| &filter.query)
+    }
+
+    /// Sets the filter query of a column, removing the filter if the query is
+    /// empty.
+    pub fn set_filter(&mut self, column: &str, query: impl Into<String>) {
+        let query = query.into();
+        self.filters.retain(|filter| filter.column != column);
+
+        if !query.is_empty() {
+            self.filters.push(ColumnFilter::new(column, query));
+        }
+    }
+
+    /// Applies the query to a list of rows, showing a page of them starting
+    /// at zero. `compare` orders two rows by a column in ascending order, and
+    /// `matches` checks whether a row matches a column's filter query. Both
+    /// are given the ID of the column. Rows that compare equal on every
+    /// sorted column keep their original order.
+    ///
+    /// ```
+    /// # use leptonic::{text_matches, TableQuery};
+    /// let people = [("Ada", 36), ("Grace", 85), ("Alan", 41), ("Linus", 54)];
+    /// let mut query = TableQuery::default().page_size(2);
+    /// query.toggle_sort("age", false);
+    /// query.toggle_sort("age", false);
+    /// query.set_filter("name", "a");
+    ///
+    /// let rows = query.apply(
+    ///     &people,
+    ///     0,
+    ///     |_, a, b| a.1.cmp(&b.1),
+    ///     |_, row, query| text_matches(row.0, query),
+    /// );
+    /// assert_eq!(rows.indices, vec![1, 2]);
+    /// assert_eq!(rows.total, 3);
+    /// assert_eq!(rows.page_count, 2);
+    /// ```
+    pub fn apply<T>(
+        &self,
+        rows: &[T],
+        page: usize,
+        compare: impl Fn(&str, &T, &T) -> Ordering,
+        matches: impl Fn(&str, &T, &str) -> bool,
+    ) -> TableRows {
+        let mut indices = (0..rows.len())
+            .filter(|&index| {
+                self.filters
+                    .iter()
+                    .all(|filter| matches(&filter.column, &rows[index], &filter.query))
+            })
+            .collect::<Vec<_>>();
+
+        if !self.sorts.is_empty() {
+            indices.sort_by(|&a, &b| {
+                self.sorts
+                    .iter()
+                    .map(|sort| {
+                        sort.direction
+                            .apply(compare(&sort.column, &rows[a], &rows[b]))
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+
+        let total = indices.len();
+        let page_size = self.page_size.unwrap_or(0);
+        let page_count = page_count(total, page_size);
+        let page = page.min(page_count - 1);
+        let range = page_range(total, page, page_size);
+        indices.truncate(range.end);
+        indices.drain(..range.start);
+
+        TableRows {
+            indices,
+            total,
+            page,
+            page_count,
+        }
+    }
+}
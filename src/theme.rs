@@ -1,6 +1,8 @@
+use crate::color::*;
 use csscolorparser::Color;
-use js_sys::encode_uri_component;
 use leptos::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 use std::time::Duration;
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
 
@@ -10,6 +12,24 @@ const STYLES: &str = include_str!("assets/css/leptonic.css");
 /// The content of the checkmark SVG.
 const CHECKMARK_ICON: &str = include_str!("assets/svg/check-solid.svg");
 
+/// The content of the downward angle SVG.
+const ANGLE_DOWN_ICON: &str = include_str!("assets/svg/angle-down-solid.svg");
+
+/// The content of the leftward angle SVG.
+const ANGLE_LEFT_ICON: &str = include_str!("assets/svg/angle-left-solid.svg");
+
+/// The content of the rightward angle SVG.
+const ANGLE_RIGHT_ICON: &str = include_str!("assets/svg/angle-right-solid.svg");
+
+/// The content of the calendar SVG.
+const CALENDAR_ICON: &str = include_str!("assets/svg/calendar-days-solid.svg");
+
+/// The content of the xmark SVG.
+const XMARK_ICON: &str = include_str!("assets/svg/xmark-solid.svg");
+
+/// The content of the minus SVG.
+const MINUS_ICON: &str = include_str!("assets/svg/minus-solid.svg");
+
 /// Fonts to fall back to if no other fonts are available.
 const FALLBACK_FONTS: &[&str] = &[
     "system-ui",
@@ -26,30 +46,30 @@ const FALLBACK_FONTS: &[&str] = &[
 ];
 
 /// The set of background colors for dark mode.
-const DARK_BACKGROUND_COLORS: &[Color; 6] = &[
-    Color::new(0.10196, 0.10980, 0.12157, 1.0),
-    Color::new(0.11765, 0.12549, 0.13725, 1.0),
-    Color::new(0.13333, 0.14118, 0.15294, 1.0),
-    Color::new(0.14902, 0.15686, 0.16863, 1.0),
-    Color::new(0.16471, 0.17255, 0.18431, 1.0),
-    Color::new(0.18039, 0.18824, 0.20000, 1.0),
+const DARK_BACKGROUND_COLORS: [Rgba8; 6] = [
+    [26, 28, 31, 255],
+    [30, 32, 35, 255],
+    [34, 36, 39, 255],
+    [38, 40, 43, 255],
+    [42, 44, 47, 255],
+    [46, 48, 51, 255],
 ];
 
 /// The set of background colors for light mode.
-const LIGHT_BACKGROUND_COLORS: &[Color; 6] = &[
-    Color::new(1.0, 1.0, 1.0, 1.0),
-    Color::new(1.0, 1.0, 1.0, 1.0),
-    Color::new(1.0, 1.0, 1.0, 1.0),
-    Color::new(1.0, 1.0, 1.0, 1.0),
-    Color::new(1.0, 1.0, 1.0, 1.0),
-    Color::new(1.0, 1.0, 1.0, 1.0),
+const LIGHT_BACKGROUND_COLORS: [Rgba8; 6] = [
+    [255, 255, 255, 255],
+    [255, 255, 255, 255],
+    [255, 255, 255, 255],
+    [255, 255, 255, 255],
+    [255, 255, 255, 255],
+    [255, 255, 255, 255],
 ];
 
 /// White text color for use in dark mode.
-const DARK_TEXT_COLOR: Color = Color::new(1.0, 1.0, 1.0, 1.0);
+const DARK_TEXT_COLOR: Rgba8 = [255, 255, 255, 255];
 
 /// Black text color for use in light mode.
-const LIGHT_TEXT_COLOR: Color = Color::new(0.0, 0.0, 0.0, 1.0);
+const LIGHT_TEXT_COLOR: Rgba8 = [0, 0, 0, 255];
 
 /// A filter to apply to SVGs to make them appear white in dark mode.
 const DARK_SVG_FILTER: &str =
@@ -68,40 +88,49 @@ const LIGHT_SVG_FILTER_DISABLED: &str =
     "invert(18%) sepia(5%) saturate(0%) hue-rotate(253deg) brightness(96%) contrast(92%)";
 
 /// Standard border color for dark mode.
-const DARK_BORDER_COLOR: Color = Color::new(0.29020, 0.29804, 0.30980, 1.0);
+const DARK_BORDER_COLOR: Rgba8 = [74, 76, 79, 255];
 
 /// Standard border color for a focused element in dark mode.
-const DARK_FOCUS_BORDER_COLOR: Color = Color::new(0.41569, 0.42353, 0.43529, 1.0);
+const DARK_FOCUS_BORDER_COLOR: Rgba8 = [106, 108, 111, 255];
 
 /// Standard border color for light mode.
-const LIGHT_BORDER_COLOR: Color = Color::new(0.69020, 0.69804, 0.70980, 1.0);
+const LIGHT_BORDER_COLOR: Rgba8 = [176, 178, 181, 255];
 
 /// Standard border color for a focused element in light mode.
-const LIGHT_FOCUS_BORDER_COLOR: Color = Color::new(0.56471, 0.57255, 0.58431, 1.0);
+const LIGHT_FOCUS_BORDER_COLOR: Rgba8 = [144, 146, 149, 255];
 
-/// Median color, used for color mixing.
-const DEFAULT_MID_COLOR: Color = Color::new(0.5, 0.5, 0.5, 1.0);
+/// Median color, used for color mixing. This matches `--leptonic-mid-color`
+/// in the stylesheet.
+pub(crate) const DEFAULT_MID_COLOR: Rgba8 = [127, 127, 127, 255];
 
 /// The default color for error text.
-const DEFAULT_ERROR_COLOR: Color = Color::new(0.81176, 0.0, 0.0, 1.0);
+pub(crate) const DEFAULT_ERROR_COLOR: Rgba8 = [207, 0, 0, 255];
 
 /// The default primary color.
-const DEFAULT_PRIMARY_COLOR: Color = Color::new(0.15686, 0.31765, 1.0, 1.0);
+pub(crate) const DEFAULT_PRIMARY_COLOR: Rgba8 = [40, 81, 255, 255];
 
 /// The default secondary color.
-const DEFAULT_SECONDARY_COLOR: Color = Color::new(0.35294, 0.36078, 0.37255, 1.0);
+pub(crate) const DEFAULT_SECONDARY_COLOR: Rgba8 = [90, 92, 95, 255];
 
-/// The default transparent color.
-const DEFAULT_TRANSPARENT_COLOR: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+/// The default transparent color. This matches
+/// `--leptonic-transparent-color` in the stylesheet.
+pub(crate) const DEFAULT_TRANSPARENT_COLOR: Rgba8 = [0, 0, 0, 0];
 
 /// The default danger color.
-const DEFAULT_DANGER_COLOR: Color = Color::new(0.68627, 0.0, 0.0, 1.0);
+pub(crate) const DEFAULT_DANGER_COLOR: Rgba8 = [175, 0, 0, 255];
+
+/// The percentage to darken a color when hovering.
+const HOVER_DARKEN_AMOUNT: u8 = 15;
 
-/// The amount to darken a color when hovering.
-const HOVER_DARKEN_AMOUNT: f64 = 0.15;
+/// The percentage to darken a color when active.
+const ACTIVE_DARKEN_AMOUNT: u8 = 25;
 
-/// The amount to darken a color when active.
-const ACTIVE_DARKEN_AMOUNT: f64 = 0.25;
+/// The class applied to the root element while a theme change transitions.
+const THEME_TRANSITION_CLASS: &str = "leptonic-theme-transition";
+
+/// How long a theme change transition lasts. This should match
+/// `--leptonic-theme-transition-duration` in the stylesheet.
+const THEME_TRANSITION_DURATION: Duration = Duration::from_millis(300);
 
 /// The color mode. Defaults to dark mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -125,11 +154,242 @@ impl ColorMode {
     }
 }
 
+/// The direction in which text is laid out. Defaults to left-to-right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextDirection {
+    /// Left-to-right.
+    #[default]
+    Ltr,
+    /// Right-to-left.
+    Rtl,
+}
+
+impl TextDirection {
+    /// Gets the value of the HTML `dir` attribute for this direction.
+    pub fn dir_name(&self) -> &'static str {
+        match *self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+}
+
+/// The kind of value held by a theme variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeVariableKind {
+    /// A color.
+    Color,
+    /// A comma-separated list of font families.
+    FontFamily,
+    /// A length, such as a border radius or padding.
+    Dimension,
+    /// A CSS filter function list.
+    Filter,
+    /// A CSS image, such as a `url(...)`.
+    Image,
+}
+
+/// A CSS variable derived from a theme.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeVariable {
+    /// The variable name, without the leading `--leptonic-`.
+    pub name: String,
+    /// The CSS value of the variable.
+    pub value: String,
+    /// The kind of value.
+    pub kind: ThemeVariableKind,
+}
+
+impl ThemeVariable {
+    /// Gets the full CSS custom property name, e.g. `--leptonic-text-color`.
+    pub fn css_name(&self) -> String {
+        format!("--leptonic-{}", self.name)
+    }
+}
+
+/// A value that can be assigned to a CSS variable.
+trait CssValue {
+    /// The kind of value.
+    const KIND: ThemeVariableKind;
+
+    /// Gets the CSS representation of the value.
+    fn css_value(&self) -> String;
+}
+
+impl CssValue for Color {
+    const KIND: ThemeVariableKind = ThemeVariableKind::Color;
+
+    fn css_value(&self) -> String {
+        self.to_hex_string()
+    }
+}
+
+impl CssValue for String {
+    const KIND: ThemeVariableKind = ThemeVariableKind::Dimension;
+
+    fn css_value(&self) -> String {
+        self.clone()
+    }
+}
+
+/// Defines a set of style overrides for a single component. Each override
+/// maps to a component-scoped CSS variable, which falls back to the
+/// stylesheet's default when the override is not set.
+macro_rules! component_overrides {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $( $field:ident, $set_field:ident: $ty:ty => $var:literal, $desc:literal; )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq)]
+        pub struct $name {
+            $(
+                #[doc = concat!("The ", $desc, ", or `None` to use the default.")]
+                pub $field: Option<$ty>,
+            )*
+        }
+
+        impl $name {
+            $(
+                #[doc = concat!("Sets the ", $desc, ".")]
+                pub fn $set_field(&mut self, $field: impl Into<$ty>) {
+                    self.$field = Some($field.into());
+                }
+            )*
+
+            $(
+                #[doc = concat!("Sets the ", $desc, ".")]
+                pub fn $field(mut self, $field: impl Into<$ty>) -> Self {
+                    self.$set_field($field);
+                    self
+                }
+            )*
+
+            /// Gets the CSS variables corresponding to each override.
+            fn css_vars(&self) -> Vec<(&'static str, Option<String>, ThemeVariableKind)> {
+                vec![
+                    $(
+                        (
+                            $var,
+                            self.$field.as_ref().map(CssValue::css_value),
+                            <$ty as CssValue>::KIND,
+                        ),
+                    )*
+                ]
+            }
+        }
+    };
+}
+
+component_overrides! {
+    /// Style overrides for buttons.
+    ButtonOverrides {
+        border_radius, set_border_radius: String => "button-border-radius", "border radius";
+        padding, set_padding: String => "button-padding", "padding";
+    }
+}
+
+component_overrides! {
+    /// Style overrides for inputs, including number inputs.
+    InputOverrides {
+        background_color, set_background_color: Color => "input-background-color", "background color";
+        text_color, set_text_color: Color => "input-text-color", "text color";
+        border_color, set_border_color: Color => "input-border-color", "border color";
+        focus_border_color, set_focus_border_color: Color => "input-focus-border-color", "border color when focused";
+        border_radius, set_border_radius: String => "input-border-radius", "border radius";
+    }
+}
+
+component_overrides! {
+    /// Style overrides for textareas. Unset values fall back to the input
+    /// overrides.
+    TextAreaOverrides {
+        background_color, set_background_color: Color => "textarea-background-color", "background color";
+        text_color, set_text_color: Color => "textarea-text-color", "text color";
+        border_color, set_border_color: Color => "textarea-border-color", "border color";
+        focus_border_color, set_focus_border_color: Color => "textarea-focus-border-color", "border color when focused";
+        border_radius, set_border_radius: String => "textarea-border-radius", "border radius";
+    }
+}
+
+component_overrides! {
+    /// Style overrides for checkboxes.
+    CheckboxOverrides {
+        checked_color, set_checked_color: Color => "checkbox-background-color-checked", "background color when checked";
+        unchecked_color, set_unchecked_color: Color => "checkbox-background-color-unchecked", "background color when unchecked";
+        border_color, set_border_color: Color => "checkbox-border-color", "border color";
+        border_radius, set_border_radius: String => "checkbox-border-radius", "border radius";
+        size, set_size: String => "checkbox-size", "size of the box";
+    }
+}
+
+component_overrides! {
+    /// Style overrides for switches.
+    SwitchOverrides {
+        checked_color, set_checked_color: Color => "switch-background-color-checked", "background color when on";
+        unchecked_color, set_unchecked_color: Color => "switch-background-color-unchecked", "background color when off";
+        head_color, set_head_color: Color => "switch-head-background-color", "color of the switch head";
+        border_color, set_border_color: Color => "switch-border-color", "border color";
+        height, set_height: String => "switch-height", "height of the switch";
+    }
+}
+
+component_overrides! {
+    /// Style overrides for error messages.
+    ErrorOverrides {
+        text_color, set_text_color: Color => "error-text-color", "text color";
+    }
+}
+
+/// Style overrides for individual components.
+///
+/// ```
+/// # use leptonic::{ButtonOverrides, InputOverrides, Theme};
+/// let theme = Theme::default()
+///     .input_overrides(InputOverrides::default().border_color((255, 0, 0)))
+///     .button_overrides(ButtonOverrides::default().border_radius("0"));
+///
+/// assert_eq!(theme.components.button.border_radius.as_deref(), Some("0"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComponentOverrides {
+    /// Button style overrides.
+    pub button: ButtonOverrides,
+    /// Input style overrides.
+    pub input: InputOverrides,
+    /// Textarea style overrides.
+    pub textarea: TextAreaOverrides,
+    /// Checkbox style overrides.
+    pub checkbox: CheckboxOverrides,
+    /// Switch style overrides.
+    pub switch: SwitchOverrides,
+    /// Error message style overrides.
+    pub error: ErrorOverrides,
+}
+
+impl ComponentOverrides {
+    /// Gets the CSS variables corresponding to every component override.
+    fn css_vars(&self) -> Vec<(&'static str, Option<String>, ThemeVariableKind)> {
+        let mut vars = self.button.css_vars();
+        vars.extend(self.input.css_vars());
+        vars.extend(self.textarea.css_vars());
+        vars.extend(self.checkbox.css_vars());
+        vars.extend(self.switch.css_vars());
+        vars.extend(self.error.css_vars());
+        vars
+    }
+}
+
 /// A styling theme.
 #[derive(Debug, Clone)]
 pub struct Theme {
     /// The theme's color mode.
     pub color_mode: ColorMode,
+    /// The direction in which text is laid out, or `None` to leave the
+    /// document's `dir` attribute as it is.
+    pub direction: Option<TextDirection>,
     /// The primary color.
     pub primary_color: Color,
     /// The secondary color.
@@ -140,17 +400,29 @@ pub struct Theme {
     pub error_color: Color,
     /// The fonts to be applied to all elements.
     pub fonts: Vec<String>,
+    /// Whether theme changes should transition smoothly.
+    pub transitions: bool,
+    /// Style overrides for individual components.
+    pub components: ComponentOverrides,
+    /// Colors derived at compile time by the [`theme!`](crate::theme!)
+    /// macro. These are only used while they still match the theme's colors,
+    /// so the theme may be freely modified.
+    pub(crate) precomputed_colors: Option<&'static ThemeColors>,
 }
 
 impl Default for Theme {
     fn default() -> Self {
         Self {
             color_mode: ColorMode::default(),
-            primary_color: DEFAULT_PRIMARY_COLOR,
-            secondary_color: DEFAULT_SECONDARY_COLOR,
-            danger_color: DEFAULT_DANGER_COLOR,
-            error_color: DEFAULT_ERROR_COLOR,
+            direction: None,
+            primary_color: DEFAULT_PRIMARY_COLOR.into(),
+            secondary_color: DEFAULT_SECONDARY_COLOR.into(),
+            danger_color: DEFAULT_DANGER_COLOR.into(),
+            error_color: DEFAULT_ERROR_COLOR.into(),
             fonts: Vec::new(),
+            transitions: false,
+            components: ComponentOverrides::default(),
+            precomputed_colors: None,
         }
     }
 }
@@ -171,6 +443,11 @@ impl Theme {
         self.color_mode = ColorMode::Light;
     }
 
+    /// Sets the text direction.
+    pub fn set_direction(&mut self, direction: TextDirection) {
+        self.direction = Some(direction);
+    }
+
     /// Sets the primary color.
     pub fn set_primary_color(&mut self, primary_color: impl Into<Color>) {
         self.primary_color = primary_color.into();
@@ -201,6 +478,41 @@ impl Theme {
         self.fonts.push(font.to_owned());
     }
 
+    /// Sets whether theme changes should transition smoothly.
+    pub fn set_transitions(&mut self, transitions: bool) {
+        self.transitions = transitions;
+    }
+
+    /// Sets the button style overrides.
+    pub fn set_button_overrides(&mut self, overrides: ButtonOverrides) {
+        self.components.button = overrides;
+    }
+
+    /// Sets the input style overrides.
+    pub fn set_input_overrides(&mut self, overrides: InputOverrides) {
+        self.components.input = overrides;
+    }
+
+    /// Sets the textarea style overrides.
+    pub fn set_textarea_overrides(&mut self, overrides: TextAreaOverrides) {
+        self.components.textarea = overrides;
+    }
+
+    /// Sets the checkbox style overrides.
+    pub fn set_checkbox_overrides(&mut self, overrides: CheckboxOverrides) {
+        self.components.checkbox = overrides;
+    }
+
+    /// Sets the switch style overrides.
+    pub fn set_switch_overrides(&mut self, overrides: SwitchOverrides) {
+        self.components.switch = overrides;
+    }
+
+    /// Sets the error message style overrides.
+    pub fn set_error_overrides(&mut self, overrides: ErrorOverrides) {
+        self.components.error = overrides;
+    }
+
     /// Sets the color mode.
     pub fn color_mode(mut self, color_mode: ColorMode) -> Self {
         self.set_color_mode(color_mode);
@@ -219,6 +531,12 @@ impl Theme {
         self
     }
 
+    /// Sets the text direction.
+    pub fn direction(mut self, direction: TextDirection) -> Self {
+        self.set_direction(direction);
+        self
+    }
+
     /// Sets the primary color.
     pub fn primary_color(mut self, primary_color: impl Into<Color>) -> Self {
         self.set_primary_color(primary_color);
@@ -254,28 +572,124 @@ impl Theme {
         self.add_font(font);
         self
     }
-}
 
-/// Mixes two colors together. The `amount` is the amount of `color1` that
-/// should be mixed into `color2`.
-fn mix(color1: &Color, color2: &Color, amount: f64) -> Color {
-    color1.interpolate_rgb(color2, 1.0 - amount)
+    /// Sets whether theme changes should transition smoothly.
+    pub fn transitions(mut self, transitions: bool) -> Self {
+        self.set_transitions(transitions);
+        self
+    }
+
+    /// Sets the button style overrides.
+    pub fn button_overrides(mut self, overrides: ButtonOverrides) -> Self {
+        self.set_button_overrides(overrides);
+        self
+    }
+
+    /// Sets the input style overrides.
+    pub fn input_overrides(mut self, overrides: InputOverrides) -> Self {
+        self.set_input_overrides(overrides);
+        self
+    }
+
+    /// Sets the textarea style overrides.
+    pub fn textarea_overrides(mut self, overrides: TextAreaOverrides) -> Self {
+        self.set_textarea_overrides(overrides);
+        self
+    }
+
+    /// Sets the checkbox style overrides.
+    pub fn checkbox_overrides(mut self, overrides: CheckboxOverrides) -> Self {
+        self.set_checkbox_overrides(overrides);
+        self
+    }
+
+    /// Sets the switch style overrides.
+    pub fn switch_overrides(mut self, overrides: SwitchOverrides) -> Self {
+        self.set_switch_overrides(overrides);
+        self
+    }
+
+    /// Sets the error message style overrides.
+    pub fn error_overrides(mut self, overrides: ErrorOverrides) -> Self {
+        self.set_error_overrides(overrides);
+        self
+    }
+
+    /// Derives every CSS variable applied by this theme, including the hover,
+    /// active, and disabled variants of each color. Unlike applying a theme,
+    /// this does not require a browser.
+    pub fn css_variables(&self) -> Vec<ThemeVariable> {
+        derive_variables(self, DEFAULT_MID_COLOR, DEFAULT_TRANSPARENT_COLOR)
+    }
 }
 
-/// Darkens a color by the specified amount.
-fn darken(color: &Color, amount: f64) -> Color {
-    mix(color, &Color::new(0.0, 0.0, 0.0, color.a), 1.0 - amount)
+/// A callback to be run after a theme has been applied.
+type ThemeObserver = Rc<dyn Fn(&Theme)>;
+
+/// The set of callbacks observing theme changes.
+#[derive(Clone, Default)]
+struct ThemeObservers {
+    /// The ID to give the next registered observer.
+    next_id: Rc<Cell<usize>>,
+    /// The registered observers.
+    observers: Rc<RefCell<Vec<(usize, ThemeObserver)>>>,
 }
 
-/// Determines the text color to use based on the background color.
-fn derive_text_color(background_color: &Color) -> Color {
-    if (background_color.r + background_color.g + background_color.b) / 3.0 < 0.6 {
-        DARK_TEXT_COLOR
-    } else {
-        LIGHT_TEXT_COLOR
+impl ThemeObservers {
+    /// Registers an observer, returning its ID.
+    fn add(&self, observer: ThemeObserver) -> usize {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.observers.borrow_mut().push((id, observer));
+        id
+    }
+
+    /// Unregisters an observer.
+    fn remove(&self, id: usize) {
+        self.observers
+            .borrow_mut()
+            .retain(|(observer_id, _)| *observer_id != id);
+    }
+
+    /// Calls all registered observers.
+    fn notify(&self, theme: &Theme) {
+        // Clone the list so observers may register or unregister others.
+        let observers = self
+            .observers
+            .borrow()
+            .iter()
+            .map(|(_, observer)| Rc::clone(observer))
+            .collect::<Vec<_>>();
+
+        for observer in observers {
+            observer(theme);
+        }
     }
 }
 
+/// Percent-encodes a string in the same way as JavaScript's
+/// `encodeURIComponent`.
+fn encode_uri_component(value: &str) -> String {
+    value.bytes().fold(String::new(), |mut encoded, byte| {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'!'
+            | b'~'
+            | b'*'
+            | b'\''
+            | b'('
+            | b')' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+        encoded
+    })
+}
+
 /// Encodes an SVG for use as a background image.
 fn svg_background_image(svg_content: &str) -> String {
     format!(
@@ -310,255 +724,313 @@ fn set_css_var(name: &str, value: &str) {
     );
 }
 
-/// Applies a styling theme.
-fn apply_theme(theme: &Theme) {
-    let mut fonts = theme.fonts.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
-    fonts.extend(FALLBACK_FONTS);
-    set_css_var("--leptonic-fonts", &fonts.join(", "));
-
-    let background_colors = match theme.color_mode {
-        ColorMode::Dark => DARK_BACKGROUND_COLORS,
-        ColorMode::Light => LIGHT_BACKGROUND_COLORS,
-    };
-    background_colors
-        .iter()
-        .enumerate()
-        .for_each(|(index, background_color)| {
-            set_css_var(
-                &format!("--leptonic-background-color-{}", index + 1),
-                &background_color.to_hex_string(),
-            );
-        });
-
-    let text_color = match theme.color_mode {
-        ColorMode::Dark => DARK_TEXT_COLOR,
-        ColorMode::Light => LIGHT_TEXT_COLOR,
-    };
-    set_css_var("--leptonic-text-color", &text_color.to_hex_string());
-
-    let svg_filter = match theme.color_mode {
-        ColorMode::Dark => DARK_SVG_FILTER,
-        ColorMode::Light => LIGHT_SVG_FILTER,
-    };
-    set_css_var("--leptonic-primary-svg-filter", svg_filter);
-
-    let svg_filter_disabled = match theme.color_mode {
-        ColorMode::Dark => DARK_SVG_FILTER_DISABLED,
-        ColorMode::Light => LIGHT_SVG_FILTER_DISABLED,
-    };
-    set_css_var(
-        "--leptonic-primary-svg-filter-disabled",
-        svg_filter_disabled,
-    );
-
-    let border_color = match theme.color_mode {
-        ColorMode::Dark => DARK_BORDER_COLOR,
-        ColorMode::Light => LIGHT_BORDER_COLOR,
-    };
-    set_css_var("--leptonic-border-color", &border_color.to_hex_string());
-
-    let focus_border_color = match theme.color_mode {
-        ColorMode::Dark => DARK_FOCUS_BORDER_COLOR,
-        ColorMode::Light => LIGHT_FOCUS_BORDER_COLOR,
-    };
-    set_css_var(
-        "--leptonic-focus-border-color",
-        &focus_border_color.to_hex_string(),
-    );
-
-    let mid_color = get_css_var("--leptonic-mid-color")
-        .parse::<Color>()
-        .unwrap_or(DEFAULT_MID_COLOR);
-
-    let transparent_color = get_css_var("--leptonic-transparent-color")
-        .parse::<Color>()
-        .unwrap_or(DEFAULT_TRANSPARENT_COLOR);
-
-    set_css_var(
-        "--leptonic-text-color-disabled",
-        &mix(&text_color, &mid_color, 0.4).to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-primary-color",
-        &theme.primary_color.to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-primary-color-hover",
-        &darken(&theme.primary_color, HOVER_DARKEN_AMOUNT).to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-primary-color-active",
-        &darken(&theme.primary_color, ACTIVE_DARKEN_AMOUNT).to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-primary-color-disabled",
-        &mix(&theme.primary_color, &mid_color, 0.3).to_hex_string(),
-    );
-
-    let primary_text_color = derive_text_color(&theme.primary_color);
-    set_css_var(
-        "--leptonic-primary-text-color",
-        &primary_text_color.to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-primary-text-color-disabled",
-        &mix(&primary_text_color, &mid_color, 0.4).to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-primary-text-label-color-1",
-        &mix(&primary_text_color, &mid_color, 0.7).to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-primary-text-label-color-2",
-        &mix(&primary_text_color, &mid_color, 0.6).to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-primary-text-label-color-3",
-        &mix(&primary_text_color, &mid_color, 0.5).to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-secondary-color",
-        &theme.secondary_color.to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-secondary-color-hover",
-        &darken(&theme.secondary_color, HOVER_DARKEN_AMOUNT).to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-secondary-color-active",
-        &darken(&theme.secondary_color, ACTIVE_DARKEN_AMOUNT).to_hex_string(),
-    );
-
-    set_css_var(
-        "--leptonic-secondary-color-disabled",
-        &mix(&theme.secondary_color, &mid_color, 0.5).to_hex_string(),
-    );
-
-    let secondary_text_color = derive_text_color(&theme.secondary_color);
-    set_css_var(
-        "--leptonic-secondary-text-color",
-        &secondary_text_color.to_hex_string(),
-    );
+/// Removes a CSS variable, reverting it to the value from the stylesheet.
+fn remove_css_var(name: &str) {
+    let name = name.to_owned();
 
-    set_css_var(
-        "--leptonic-secondary-text-color-disabled",
-        &mix(&secondary_text_color, &mid_color, 0.4).to_hex_string(),
+    set_timeout(
+        move || {
+            let root = document().document_element().unwrap();
+            let root: web_sys::HtmlElement = root.dyn_into().unwrap_throw();
+            root.style().remove_property(&name).unwrap();
+        },
+        Duration::ZERO,
     );
+}
 
-    set_css_var(
-        "--leptonic-secondary-text-label-color-1",
-        &mix(&secondary_text_color, &mid_color, 0.7).to_hex_string(),
-    );
+/// Temporarily enables color transitions on all elements, so that the next
+/// theme applied fades in rather than snapping. Any previously scheduled end
+/// of a transition is cancelled.
+fn start_theme_transition(pending_end: &Cell<Option<TimeoutHandle>>) {
+    let root = document().document_element().unwrap();
+    root.class_list().add_1(THEME_TRANSITION_CLASS).unwrap();
 
-    set_css_var(
-        "--leptonic-secondary-text-label-color-2",
-        &mix(&secondary_text_color, &mid_color, 0.6).to_hex_string(),
-    );
+    if let Some(handle) = pending_end.take() {
+        handle.clear();
+    }
 
-    set_css_var(
-        "--leptonic-secondary-text-label-color-3",
-        &mix(&secondary_text_color, &mid_color, 0.5).to_hex_string(),
-    );
+    let handle = set_timeout_with_handle(
+        move || {
+            _ = root.class_list().remove_1(THEME_TRANSITION_CLASS);
+        },
+        THEME_TRANSITION_DURATION,
+    )
+    .ok();
+    pending_end.set(handle);
+}
 
-    set_css_var(
-        "--leptonic-transparent-color-hover",
-        &darken(&transparent_color, HOVER_DARKEN_AMOUNT).to_hex_string(),
-    );
+/// The inputs from which a theme's colors are derived.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ColorInputs {
+    pub(crate) color_mode: ColorMode,
+    pub(crate) primary_color: Rgba8,
+    pub(crate) secondary_color: Rgba8,
+    pub(crate) danger_color: Rgba8,
+    pub(crate) error_color: Rgba8,
+    pub(crate) mid_color: Rgba8,
+    pub(crate) transparent_color: Rgba8,
+}
 
-    set_css_var(
-        "--leptonic-transparent-color-active",
-        &darken(&transparent_color, ACTIVE_DARKEN_AMOUNT).to_hex_string(),
-    );
+/// The number of color variables derived from a theme.
+const THEME_COLOR_COUNT: usize = 45;
+
+/// The full set of color variables derived from a theme, along with the
+/// inputs they were derived from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeColors {
+    /// The inputs the colors were derived from.
+    inputs: ColorInputs,
+    /// Each variable name and its color.
+    colors: [(&'static str, Rgba8); THEME_COLOR_COUNT],
+}
 
-    let transparent_text_color = text_color.clone();
-    set_css_var(
-        "--leptonic-transparent-text-color",
-        &transparent_text_color.to_hex_string(),
-    );
+/// Derives a color along with its hover, active, and disabled variants.
+const fn interactive_colors(color: Rgba8, mid_color: Rgba8, disabled_amount: u8) -> [Rgba8; 4] {
+    [
+        color,
+        darken(color, HOVER_DARKEN_AMOUNT),
+        darken(color, ACTIVE_DARKEN_AMOUNT),
+        mix(color, mid_color, disabled_amount),
+    ]
+}
 
-    set_css_var(
-        "--leptonic-transparent-text-color-disabled",
-        &mix(&transparent_text_color, &mid_color, 0.4).to_hex_string(),
-    );
+/// Derives a text color along with its disabled and label variants.
+const fn text_colors(text_color: Rgba8, mid_color: Rgba8) -> [Rgba8; 5] {
+    [
+        text_color,
+        mix(text_color, mid_color, 40),
+        mix(text_color, mid_color, 70),
+        mix(text_color, mid_color, 60),
+        mix(text_color, mid_color, 50),
+    ]
+}
 
-    set_css_var(
-        "--leptonic-transparent-text-label-color-1",
-        &mix(&transparent_text_color, &mid_color, 0.7).to_hex_string(),
-    );
+/// Determines the text color to use based on the background color.
+const fn derive_text_color(background_color: Rgba8) -> Rgba8 {
+    if is_dark(background_color) {
+        DARK_TEXT_COLOR
+    } else {
+        LIGHT_TEXT_COLOR
+    }
+}
 
-    set_css_var(
-        "--leptonic-transparent-text-label-color-2",
-        &mix(&transparent_text_color, &mid_color, 0.6).to_hex_string(),
-    );
+/// Derives every color variable from a set of inputs.
+pub(crate) const fn derive_colors(inputs: ColorInputs) -> ThemeColors {
+    let (background, text, border, focus_border) = match inputs.color_mode {
+        ColorMode::Dark => (
+            DARK_BACKGROUND_COLORS,
+            DARK_TEXT_COLOR,
+            DARK_BORDER_COLOR,
+            DARK_FOCUS_BORDER_COLOR,
+        ),
+        ColorMode::Light => (
+            LIGHT_BACKGROUND_COLORS,
+            LIGHT_TEXT_COLOR,
+            LIGHT_BORDER_COLOR,
+            LIGHT_FOCUS_BORDER_COLOR,
+        ),
+    };
+    let mid = inputs.mid_color;
+    let transparent = inputs.transparent_color;
+
+    let primary = interactive_colors(inputs.primary_color, mid, 30);
+    let primary_text = text_colors(derive_text_color(inputs.primary_color), mid);
+    let secondary = interactive_colors(inputs.secondary_color, mid, 50);
+    let secondary_text = text_colors(derive_text_color(inputs.secondary_color), mid);
+    let transparent_text = text_colors(text, mid);
+    let danger = interactive_colors(inputs.danger_color, mid, 50);
+    let danger_text = text_colors(derive_text_color(inputs.danger_color), mid);
+
+    ThemeColors {
+        inputs,
+        colors: [
+            ("background-color-1", background[0]),
+            ("background-color-2", background[1]),
+            ("background-color-3", background[2]),
+            ("background-color-4", background[3]),
+            ("background-color-5", background[4]),
+            ("background-color-6", background[5]),
+            ("text-color", text),
+            ("border-color", border),
+            ("focus-border-color", focus_border),
+            ("text-color-disabled", mix(text, mid, 40)),
+            ("primary-color", primary[0]),
+            ("primary-color-hover", primary[1]),
+            ("primary-color-active", primary[2]),
+            ("primary-color-disabled", primary[3]),
+            ("primary-text-color", primary_text[0]),
+            ("primary-text-color-disabled", primary_text[1]),
+            ("primary-text-label-color-1", primary_text[2]),
+            ("primary-text-label-color-2", primary_text[3]),
+            ("primary-text-label-color-3", primary_text[4]),
+            ("secondary-color", secondary[0]),
+            ("secondary-color-hover", secondary[1]),
+            ("secondary-color-active", secondary[2]),
+            ("secondary-color-disabled", secondary[3]),
+            ("secondary-text-color", secondary_text[0]),
+            ("secondary-text-color-disabled", secondary_text[1]),
+            ("secondary-text-label-color-1", secondary_text[2]),
+            ("secondary-text-label-color-2", secondary_text[3]),
+            ("secondary-text-label-color-3", secondary_text[4]),
+            (
+                "transparent-color-hover",
+                darken(transparent, HOVER_DARKEN_AMOUNT),
+            ),
+            (
+                "transparent-color-active",
+                darken(transparent, ACTIVE_DARKEN_AMOUNT),
+            ),
+            ("transparent-text-color", transparent_text[0]),
+            ("transparent-text-color-disabled", transparent_text[1]),
+            ("transparent-text-label-color-1", transparent_text[2]),
+            ("transparent-text-label-color-2", transparent_text[3]),
+            ("transparent-text-label-color-3", transparent_text[4]),
+            ("danger-color", danger[0]),
+            ("danger-color-hover", danger[1]),
+            ("danger-color-active", danger[2]),
+            ("danger-color-disabled", danger[3]),
+            ("danger-text-color", danger_text[0]),
+            ("danger-text-color-disabled", danger_text[1]),
+            ("danger-text-label-color-1", danger_text[2]),
+            ("danger-text-label-color-2", danger_text[3]),
+            ("danger-text-label-color-3", danger_text[4]),
+            ("error-color", inputs.error_color),
+        ],
+    }
+}
 
-    set_css_var(
-        "--leptonic-transparent-text-label-color-3",
-        &mix(&transparent_text_color, &mid_color, 0.5).to_hex_string(),
-    );
+/// Derives every CSS variable from a theme, using the given median and
+/// transparent colors for color mixing.
+fn derive_variables(
+    theme: &Theme,
+    mid_color: Rgba8,
+    transparent_color: Rgba8,
+) -> Vec<ThemeVariable> {
+    let mut vars = Vec::new();
 
-    set_css_var(
-        "--leptonic-danger-color",
-        &theme.danger_color.to_hex_string(),
-    );
+    let mut fonts = theme.fonts.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+    fonts.extend(FALLBACK_FONTS);
+    vars.push(ThemeVariable {
+        name: "fonts".to_owned(),
+        value: fonts.join(", "),
+        kind: ThemeVariableKind::FontFamily,
+    });
 
-    set_css_var(
-        "--leptonic-danger-color-hover",
-        &darken(&theme.danger_color, HOVER_DARKEN_AMOUNT).to_hex_string(),
-    );
+    let inputs = ColorInputs {
+        color_mode: theme.color_mode,
+        primary_color: theme.primary_color.to_rgba8(),
+        secondary_color: theme.secondary_color.to_rgba8(),
+        danger_color: theme.danger_color.to_rgba8(),
+        error_color: theme.error_color.to_rgba8(),
+        mid_color,
+        transparent_color,
+    };
+    let colors = match theme.precomputed_colors {
+        Some(precomputed) if precomputed.inputs == inputs => *precomputed,
+        _ => derive_colors(inputs),
+    };
+    vars.extend(colors.colors.iter().map(|(name, color)| ThemeVariable {
+        name: (*name).to_owned(),
+        value: hex_string(*color),
+        kind: ThemeVariableKind::Color,
+    }));
+
+    let (svg_filter, svg_filter_disabled) = match theme.color_mode {
+        ColorMode::Dark => (DARK_SVG_FILTER, DARK_SVG_FILTER_DISABLED),
+        ColorMode::Light => (LIGHT_SVG_FILTER, LIGHT_SVG_FILTER_DISABLED),
+    };
+    vars.push(ThemeVariable {
+        name: "primary-svg-filter".to_owned(),
+        value: svg_filter.to_owned(),
+        kind: ThemeVariableKind::Filter,
+    });
+    vars.push(ThemeVariable {
+        name: "primary-svg-filter-disabled".to_owned(),
+        value: svg_filter_disabled.to_owned(),
+        kind: ThemeVariableKind::Filter,
+    });
 
-    set_css_var(
-        "--leptonic-danger-color-active",
-        &darken(&theme.danger_color, ACTIVE_DARKEN_AMOUNT).to_hex_string(),
-    );
+    vars.push(ThemeVariable {
+        name: "checkmark-icon".to_owned(),
+        value: svg_background_image(CHECKMARK_ICON),
+        kind: ThemeVariableKind::Image,
+    });
+    vars.push(ThemeVariable {
+        name: "dash-icon".to_owned(),
+        value: svg_background_image(MINUS_ICON),
+        kind: ThemeVariableKind::Image,
+    });
+    vars.push(ThemeVariable {
+        name: "angle-down-icon".to_owned(),
+        value: svg_background_image(ANGLE_DOWN_ICON),
+        kind: ThemeVariableKind::Image,
+    });
+    vars.push(ThemeVariable {
+        name: "angle-left-icon".to_owned(),
+        value: svg_background_image(ANGLE_LEFT_ICON),
+        kind: ThemeVariableKind::Image,
+    });
+    vars.push(ThemeVariable {
+        name: "angle-right-icon".to_owned(),
+        value: svg_background_image(ANGLE_RIGHT_ICON),
+        kind: ThemeVariableKind::Image,
+    });
+    vars.push(ThemeVariable {
+        name: "calendar-icon".to_owned(),
+        value: svg_background_image(CALENDAR_ICON),
+        kind: ThemeVariableKind::Image,
+    });
+    vars.push(ThemeVariable {
+        name: "xmark-icon".to_owned(),
+        value: svg_background_image(XMARK_ICON),
+        kind: ThemeVariableKind::Image,
+    });
 
-    set_css_var(
-        "--leptonic-danger-color-disabled",
-        &mix(&theme.danger_color, &mid_color, 0.5).to_hex_string(),
-    );
+    for (name, value, kind) in theme.components.css_vars() {
+        if let Some(value) = value {
+            vars.push(ThemeVariable {
+                name: name.to_owned(),
+                value,
+                kind,
+            });
+        }
+    }
 
-    let danger_text_color = derive_text_color(&theme.danger_color);
-    set_css_var(
-        "--leptonic-danger-text-color",
-        &danger_text_color.to_hex_string(),
-    );
+    vars
+}
 
-    set_css_var(
-        "--leptonic-danger-text-color-disabled",
-        &mix(&danger_text_color, &mid_color, 0.4).to_hex_string(),
-    );
+/// Sets the document's `dir` attribute to a theme's text direction. If the
+/// theme has no direction, the attribute is only removed when it was set by
+/// the previous theme, so that a direction set by the application is kept.
+fn apply_direction(direction: Option<TextDirection>, previous: Option<TextDirection>) {
+    let root = document().document_element().unwrap();
 
-    set_css_var(
-        "--leptonic-danger-text-label-color-1",
-        &mix(&danger_text_color, &mid_color, 0.7).to_hex_string(),
-    );
+    match (direction, previous) {
+        (Some(direction), _) => root.set_attribute("dir", direction.dir_name()).unwrap(),
+        (None, Some(_)) => root.remove_attribute("dir").unwrap(),
+        (None, None) => {}
+    }
+}
 
-    set_css_var(
-        "--leptonic-danger-text-label-color-2",
-        &mix(&danger_text_color, &mid_color, 0.6).to_hex_string(),
-    );
+/// Applies a styling theme.
+fn apply_theme(theme: &Theme) {
+    let mid_color = get_css_var("--leptonic-mid-color")
+        .parse::<Color>()
+        .map_or(DEFAULT_MID_COLOR, |color| color.to_rgba8());
 
-    set_css_var(
-        "--leptonic-danger-text-label-color-3",
-        &mix(&danger_text_color, &mid_color, 0.5).to_hex_string(),
-    );
+    let transparent_color = get_css_var("--leptonic-transparent-color")
+        .parse::<Color>()
+        .map_or(DEFAULT_TRANSPARENT_COLOR, |color| color.to_rgba8());
 
-    set_css_var("--leptonic-error-color", &theme.error_color.to_hex_string());
+    for var in derive_variables(theme, mid_color, transparent_color) {
+        set_css_var(&var.css_name(), &var.value);
+    }
 
-    set_css_var(
-        "--leptonic-checkmark-icon",
-        &svg_background_image(CHECKMARK_ICON),
-    );
+    for (name, value, _) in theme.components.css_vars() {
+        if value.is_none() {
+            remove_css_var(&format!("--leptonic-{name}"));
+        }
+    }
 }
 
 /// Injects all library styles into the document head. If the styles are
@@ -604,16 +1076,90 @@ fn inject_styles(cx: Scope) {
 /// disposed, the styles will be removed. For these reasons, this should
 /// probably be called immediately and at the highest level of the
 /// application.
+///
+/// If the theme's `transitions` flag is enabled, colors will fade smoothly
+/// whenever the theme changes. The initial theme is always applied
+/// instantly, and users who prefer reduced motion will not see the
+/// transition.
 pub fn use_theme(cx: Scope) -> (ReadSignal<Theme>, WriteSignal<Theme>) {
+    use_theme_with(cx, Theme::default())
+}
+
+/// Apply a styling theme, starting from the given theme rather than the
+/// default. This avoids applying the default theme only to replace it, such
+/// as with a theme built by the [`theme!`](crate::theme!) macro.
+///
+/// ```
+/// # use leptonic::{theme, use_theme_with, ColorMode};
+/// # use leptos::*;
+/// #
+/// # #[component]
+/// # fn Demo(cx: Scope) -> impl IntoView {
+/// let (theme, set_theme) = use_theme_with(cx, theme! {
+///     color_mode: ColorMode::Light,
+///     primary_color: "#6928ff",
+/// });
+/// #
+/// # view! { cx, }
+/// # }
+/// ```
+///
+/// See [`use_theme`] for details.
+pub fn use_theme_with(cx: Scope, initial: Theme) -> (ReadSignal<Theme>, WriteSignal<Theme>) {
     inject_styles(cx);
 
-    let initial_theme = Theme::default();
-    let (theme, set_theme) = create_signal(cx, initial_theme);
+    let (theme, set_theme) = create_signal(cx, initial);
+
+    let observers = ThemeObservers::default();
+    provide_context(cx, observers.clone());
 
-    create_effect(cx, move |_| {
+    let pending_transition_end = Rc::new(Cell::new(None));
+
+    create_effect(cx, move |prev: Option<Option<TextDirection>>| {
         let new_theme = theme();
+        let direction = new_theme.direction;
+
+        if prev.is_some() && new_theme.transitions {
+            start_theme_transition(&pending_transition_end);
+        }
+
+        apply_direction(direction, prev.flatten());
         apply_theme(&new_theme);
+
+        // CSS variables are set asynchronously, so observers must be too.
+        let observers = observers.clone();
+        set_timeout(move || observers.notify(&new_theme), Duration::ZERO);
+        direction
     });
 
     (theme, set_theme)
 }
+
+/// Registers a callback to be run each time a theme has been applied,
+/// including the initial theme. This is useful for anything that reads the
+/// theme's CSS variables directly, such as canvas-based rendering.
+///
+/// ```
+/// # use leptonic::{on_theme_applied, use_theme};
+/// # use leptos::*;
+/// #
+/// # #[component]
+/// # fn Demo(cx: Scope) -> impl IntoView {
+/// # let (theme, set_theme) = use_theme(cx);
+/// on_theme_applied(cx, |theme| {
+///     log!("Now in {:?} mode", theme.color_mode);
+/// });
+/// #
+/// # view! { cx, }
+/// # }
+/// ```
+///
+/// The callback will be unregistered when the scope is disposed. This must be
+/// called within a scope beneath the one in which [`use_theme`] was called.
+pub fn on_theme_applied(cx: Scope, callback: impl Fn(&Theme) + 'static) {
+    let observers = use_context::<ThemeObservers>(cx)
+        .expect("`use_theme` must be called before `on_theme_applied`");
+    let id = observers.add(Rc::new(callback));
+
+    on_cleanup(cx, move || observers.remove(id));
+}
@@ -1,8 +1,23 @@
 #![allow(dead_code)]
 
+use crate::position::*;
 use js_sys::Math;
+use leptos::html::ElementDescriptor;
+use leptos::{
+    Memo, NodeRef, ReadSignal, Scope, SignalGet, SignalSet, SignalWith, StoredValue, WriteSignal,
+};
+use std::rc::Rc;
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
-use web_sys::{Event, HtmlElement, HtmlInputElement, HtmlTextAreaElement, InputEvent, MouseEvent};
+use web_sys::{
+    AddEventListenerOptions, Element, Event, HtmlElement, HtmlInputElement, HtmlTextAreaElement,
+    InputEvent, MouseEvent, ScrollIntoViewOptions, ScrollLogicalPosition,
+};
+
+/// A selector matching elements that can receive keyboard focus.
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button:not([disabled]), \
+    input:not([disabled]):not([type=\"hidden\"]), select:not([disabled]), \
+    textarea:not([disabled]), iframe, [contenteditable], \
+    [tabindex]:not([tabindex=\"-1\"])";
 
 /// Gets the value of an input element from an event.
 pub fn input_event_value(event: &Event) -> String {
@@ -41,6 +56,304 @@ pub fn new_id() -> String {
     hex_value
 }
 
+/// Determines whether the document is laid out right-to-left.
+pub fn is_rtl() -> bool {
+    leptos::document()
+        .document_element()
+        .and_then(|root| root.get_attribute("dir"))
+        .map_or(false, |dir| dir.eq_ignore_ascii_case("rtl"))
+}
+
+/// Focuses the element with the given ID on the next frame, if it exists.
+pub fn focus_by_id(id: String) {
+    leptos::request_animation_frame(move || {
+        if let Some(element) = leptos::document().get_element_by_id(&id) {
+            if let Ok(element) = element.dyn_into::<HtmlElement>() {
+                _ = element.focus();
+            }
+        }
+    });
+}
+
+/// Gets the elements within a root element that can receive keyboard focus,
+/// in document order.
+pub fn focusable_elements(root: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = root.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return Vec::new();
+    };
+
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+/// Scrolls the element with the given ID into view within its scrollable
+/// container, if it exists.
+pub fn scroll_into_view(id: &str) {
+    if let Some(element) = leptos::document().get_element_by_id(id) {
+        let options = ScrollIntoViewOptions::new();
+        options.set_block(ScrollLogicalPosition::Nearest);
+        element.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+}
+
+/// Adds or removes an ID in an attribute holding a space-separated list of
+/// IDs, such as `aria-describedby`, keeping any other IDs in it. The
+/// attribute is removed once it holds no IDs.
+pub fn set_id_reference(element: &Element, attribute: &str, id: &str, present: bool) {
+    let mut ids = element
+        .get_attribute(attribute)
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter(|other| *other != id)
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+
+    if present {
+        ids.push(id.to_owned());
+    }
+
+    if ids.is_empty() {
+        _ = element.remove_attribute(attribute);
+    } else {
+        _ = element.set_attribute(attribute, &ids.join(" "));
+    }
+}
+
+/// Finds the next enabled item in a list, moving forward or backward from
+/// `current`. If there is no current item, moving forward finds the first
+/// enabled item and moving backward finds the last. If `wrap` is set, the
+/// search continues from the other end of the list.
+pub fn step_enabled_index(
+    len: usize,
+    current: Option<usize>,
+    forward: bool,
+    wrap: bool,
+    is_enabled: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let mut index = current;
+
+    for _ in 0..len {
+        index = match (index, forward) {
+            (None, true) => Some(0),
+            (None, false) => Some(len - 1),
+            (Some(i), true) if i + 1 < len => Some(i + 1),
+            (Some(_), true) if wrap => Some(0),
+            (Some(i), false) if i > 0 => Some(i - 1),
+            (Some(_), false) if wrap => Some(len - 1),
+            (Some(_), _) => return current,
+        };
+
+        if index.map_or(false, &is_enabled) {
+            return index;
+        }
+    }
+
+    current
+}
+
+/// Finds the first enabled item in a list.
+pub fn first_enabled_index(len: usize, is_enabled: impl Fn(usize) -> bool) -> Option<usize> {
+    (0..len).find(|&index| is_enabled(index))
+}
+
+/// Finds the last enabled item in a list.
+pub fn last_enabled_index(len: usize, is_enabled: impl Fn(usize) -> bool) -> Option<usize> {
+    (0..len).rev().find(|&index| is_enabled(index))
+}
+
+/// Keyboard focus within a group of options of which one can be selected,
+/// such as a radio group. Only one option is reached by tabbing into the
+/// group: the selected option, or the first enabled option if none is
+/// selected. Moving between options selects them.
+pub struct RovingFocus<T: 'static> {
+    /// The ID the options' element IDs are derived from.
+    id: StoredValue<String>,
+    /// The value of each option, and whether it is enabled.
+    options: Memo<Vec<(T, bool)>>,
+    /// The selected value.
+    state: ReadSignal<T>,
+    /// The selected value setter.
+    set_state: WriteSignal<T>,
+    /// The option reached by tabbing into the group.
+    tab_stop: Memo<Option<usize>>,
+}
+
+impl<T> Clone for RovingFocus<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RovingFocus<T> {}
+
+impl<T> RovingFocus<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Creates roving focus for a group of options. `options` gets the value
+    /// of each option, and whether it is enabled.
+    pub fn new(
+        cx: Scope,
+        id: String,
+        state: ReadSignal<T>,
+        set_state: WriteSignal<T>,
+        options: impl Fn() -> Vec<(T, bool)> + 'static,
+    ) -> Self {
+        let options = leptos::create_memo(cx, move |_| options());
+        let tab_stop = leptos::create_memo(cx, move |_| {
+            let selected = state.with(|value| {
+                options.with(|opts| opts.iter().position(|(option, _)| option == value))
+            });
+
+            options.with(|opts| {
+                selected
+                    .filter(|&index| opts[index].1)
+                    .or_else(|| opts.iter().position(|&(_, enabled)| enabled))
+            })
+        });
+
+        Self {
+            id: leptos::store_value(cx, id),
+            options,
+            state,
+            set_state,
+            tab_stop,
+        }
+    }
+
+    /// Gets the ID of an option's element.
+    pub fn option_id(&self, index: usize) -> String {
+        self.id.with_value(|id| format!("{id}-{index}"))
+    }
+
+    /// Gets the number of options.
+    pub fn option_count(&self) -> usize {
+        self.options.with(|opts| opts.len())
+    }
+
+    /// Whether an option is enabled.
+    pub fn is_enabled(&self, index: usize) -> bool {
+        self.options
+            .with(|opts| opts.get(index).map_or(false, |&(_, enabled)| enabled))
+    }
+
+    /// Gets the index of the selected option.
+    pub fn selected_index(&self) -> Option<usize> {
+        self.state.with(|value| {
+            self.options
+                .with(|opts| opts.iter().position(|(option, _)| option == value))
+        })
+    }
+
+    /// Gets the index of the option reached by tabbing into the group.
+    pub fn tab_stop(&self) -> Option<usize> {
+        self.tab_stop.get()
+    }
+
+    /// Gets the `tabindex` attribute of an option's element.
+    pub fn tabindex(&self, index: usize) -> &'static str {
+        if self.tab_stop() == Some(index) {
+            "0"
+        } else {
+            "-1"
+        }
+    }
+
+    /// Finds the next enabled option after the tab stop, moving forward or
+    /// backward and wrapping around at either end.
+    pub fn step(&self, forward: bool) -> Option<usize> {
+        step_enabled_index(
+            self.option_count(),
+            self.tab_stop(),
+            forward,
+            true,
+            |index| self.is_enabled(index),
+        )
+    }
+
+    /// Finds the first enabled option.
+    pub fn first(&self) -> Option<usize> {
+        first_enabled_index(self.option_count(), |index| self.is_enabled(index))
+    }
+
+    /// Finds the last enabled option.
+    pub fn last(&self) -> Option<usize> {
+        last_enabled_index(self.option_count(), |index| self.is_enabled(index))
+    }
+
+    /// Selects and focuses an option, if it is enabled.
+    pub fn select(&self, index: usize) {
+        if let Some(value) = self.options.with(|opts| {
+            opts.get(index)
+                .filter(|&(_, enabled)| *enabled)
+                .map(|(value, _)| value.clone())
+        }) {
+            self.set_state.set(value);
+            focus_by_id(self.option_id(index));
+        }
+    }
+}
+
+/// The time after which a typeahead search is forgotten, in milliseconds.
+const TYPEAHEAD_TIMEOUT_MS: f64 = 500.0;
+
+/// Tracks the characters typed to search a list by label.
+#[derive(Debug, Clone, Default)]
+pub struct Typeahead {
+    /// The characters typed so far.
+    query: String,
+    /// The time of the last keypress, in milliseconds.
+    last_key_time: f64,
+}
+
+impl Typeahead {
+    /// Records a typed character at the given time, then finds the enabled
+    /// item whose label begins with the query so far, ignoring case. Typing a
+    /// single character repeatedly cycles through the items beginning with it.
+    pub fn search(
+        &mut self,
+        chr: char,
+        now: f64,
+        len: usize,
+        current: Option<usize>,
+        label: impl Fn(usize) -> Option<String>,
+    ) -> Option<usize> {
+        if now - self.last_key_time > TYPEAHEAD_TIMEOUT_MS {
+            self.query.clear();
+        }
+        self.last_key_time = now;
+        self.query.extend(chr.to_lowercase());
+
+        let repeated = self
+            .query
+            .chars()
+            .all(|c| c.to_lowercase().eq(chr.to_lowercase()));
+        let (query, start) = if repeated {
+            let query = chr.to_lowercase().collect::<String>();
+            (query, current.map_or(0, |i| i + 1))
+        } else {
+            (self.query.clone(), current.unwrap_or(0))
+        };
+
+        (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|&index| {
+                label(index).map_or(false, |label| label.to_lowercase().starts_with(&query))
+            })
+    }
+
+    /// Whether a search is in progress at the given time.
+    pub fn is_active(&self, now: f64) -> bool {
+        !self.query.is_empty() && now - self.last_key_time <= TYPEAHEAD_TIMEOUT_MS
+    }
+}
+
 /// Logs to the console.
 #[allow(unused_macros)]
 macro_rules! console_log {
@@ -51,3 +364,136 @@ macro_rules! console_log {
 
 #[allow(unused_imports)]
 pub(crate) use console_log;
+
+/// Gets the element a node reference points to, once it has been mounted.
+pub fn node_ref_element<T>(node_ref: NodeRef<T>) -> Option<HtmlElement>
+where
+    T: ElementDescriptor + Clone + 'static,
+{
+    node_ref.get().map(|element| (*element.into_any()).clone())
+}
+
+/// Gets an element's bounding box, relative to the viewport.
+pub fn element_rect(element: &Element) -> Rect {
+    let rect = element.get_bounding_client_rect();
+    Rect::new(rect.x(), rect.y(), rect.width(), rect.height())
+}
+
+/// Gets the visible area of the viewport, excluding any scrollbars.
+pub fn viewport_rect() -> Rect {
+    leptos::document()
+        .document_element()
+        .map(|root| {
+            Rect::new(
+                0.0,
+                0.0,
+                root.client_width() as f64,
+                root.client_height() as f64,
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// Positions an absolutely or fixed positioned floating element next to an
+/// anchor. Start and end alignments above and below the anchor are mirrored
+/// in right-to-left layouts. The placement used is exposed to styles through
+/// the `data-placement` attribute, and the arrow offset through the
+/// `--leptonic-arrow-offset` variable.
+pub fn place_floating(
+    anchor: &Element,
+    floating: &HtmlElement,
+    placement: Placement,
+    options: PositionOptions,
+) -> Position {
+    let placement = if is_rtl() && placement.side.is_vertical() {
+        Placement::new(placement.side, placement.alignment.mirrored())
+    } else {
+        placement
+    };
+    let size = Size::new(
+        floating.offset_width() as f64,
+        floating.offset_height() as f64,
+    );
+    let position = compute_position(
+        element_rect(anchor),
+        size,
+        viewport_rect(),
+        placement,
+        options,
+    );
+
+    // Positions are relative to the floating element's containing block,
+    // which is the viewport for fixed positioned elements.
+    let (origin_x, origin_y) = match floating.offset_parent() {
+        Some(parent) => {
+            let rect = element_rect(&parent);
+            (
+                rect.x + parent.client_left() as f64 - parent.scroll_left() as f64,
+                rect.y + parent.client_top() as f64 - parent.scroll_top() as f64,
+            )
+        }
+        None => (0.0, 0.0),
+    };
+
+    let style = floating.style();
+    _ = style.set_property("left", &format!("{}px", position.x - origin_x));
+    _ = style.set_property("top", &format!("{}px", position.y - origin_y));
+    _ = style.set_property(
+        "--leptonic-arrow-offset",
+        &format!("{}px", position.arrow_offset),
+    );
+    _ = floating.set_attribute("data-placement", &position.placement.placement_name());
+
+    position
+}
+
+/// Keeps a floating element positioned next to its anchor while it is open,
+/// following the anchor as the page scrolls or is resized.
+pub fn use_floating(
+    cx: Scope,
+    open: impl Fn() -> bool + 'static,
+    anchor: impl Fn() -> Option<Element> + 'static,
+    floating: impl Fn() -> Option<HtmlElement> + 'static,
+    placement: impl Fn() -> Placement + 'static,
+    options: PositionOptions,
+) {
+    let open = Rc::new(open);
+    let reposition = {
+        let open = Rc::clone(&open);
+        Rc::new(move || {
+            if open() {
+                if let (Some(anchor), Some(floating)) = (anchor(), floating()) {
+                    place_floating(&anchor, &floating, placement(), options);
+                }
+            }
+        })
+    };
+
+    // Position the element once it has been laid out.
+    {
+        let reposition = Rc::clone(&reposition);
+        leptos::create_effect(cx, move |_| {
+            reposition();
+            let reposition = Rc::clone(&reposition);
+            leptos::request_animation_frame(move || reposition());
+        });
+    }
+
+    {
+        let reposition = Rc::clone(&reposition);
+        let listener_options = AddEventListenerOptions::new();
+        listener_options.set_capture(true);
+        listener_options.set_passive(true);
+        _ = leptos_use::use_event_listener_with_options(
+            cx,
+            leptos::window(),
+            leptos::ev::scroll,
+            move |_| reposition(),
+            listener_options,
+        );
+    }
+
+    _ = leptos_use::use_event_listener(cx, leptos::window(), leptos::ev::resize, move |_| {
+        reposition()
+    });
+}
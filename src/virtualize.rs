@@ -0,0 +1,223 @@
+use std::ops::Range;
+
+/// Where to place an item when scrolling to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScrollAlign {
+    /// Scroll as little as possible to bring the item into view.
+    #[default]
+    Auto,
+    /// Align the item with the top of the viewport.
+    Start,
+    /// Center the item in the viewport.
+    Center,
+    /// Align the item with the bottom of the viewport.
+    End,
+}
+
+impl ScrollAlign {
+    /// Gets the name of the alignment.
+    pub fn align_name(&self) -> &'static str {
+        match *self {
+            Self::Auto => "auto",
+            Self::Start => "start",
+            Self::Center => "center",
+            Self::End => "end",
+        }
+    }
+}
+
+/// The heights of the items in a list.
+#[derive(Debug, Clone, PartialEq)]
+enum ItemHeights {
+    /// Every item has the same height.
+    Fixed {
+        /// The number of items.
+        count: usize,
+        /// The height of each item.
+        height: f64,
+    },
+    /// Items have their own heights.
+    Variable {
+        /// The offset of the top of each item, followed by the total height.
+        /// This always has one more entry than there are items.
+        offsets: Vec<f64>,
+    },
+}
+
+/// The vertical layout of the items in a list, used to work out which items
+/// are in view without rendering all of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListLayout(ItemHeights);
+
+impl Default for ListLayout {
+    fn default() -> Self {
+        Self::fixed(0, 0.0)
+    }
+}
+
+impl ListLayout {
+    /// Creates a layout of items that all have the same height.
+    pub fn fixed(count: usize, height: f64) -> Self {
+        Self(ItemHeights::Fixed {
+            count,
+            height: height.max(0.0),
+        })
+    }
+
+    /// Creates a layout of items with the given heights.
+    ///
+    /// ```
+    /// # use leptonic::ListLayout;
+    /// let layout = ListLayout::variable([20.0, 40.0, 30.0]);
+    /// assert_eq!(layout.len(), 3);
+    /// assert_eq!(layout.offset(2), 60.0);
+    /// assert_eq!(layout.height(1), 40.0);
+    /// assert_eq!(layout.total_height(), 90.0);
+    ///
+    /// let empty = ListLayout::variable([]);
+    /// assert!(empty.is_empty());
+    /// assert_eq!(empty.offset(0), 0.0);
+    /// assert_eq!(empty.total_height(), 0.0);
+    /// assert_eq!(empty.visible_range(0.0, 100.0, 3), 0..0);
+    /// ```
+    pub fn variable(heights: impl IntoIterator<Item = f64>) -> Self {
+        let mut offsets = vec![0.0];
+        let mut total = 0.0;
+
+        for height in heights {
+            total += height.max(0.0);
+            offsets.push(total);
+        }
+
+        Self(ItemHeights::Variable { offsets })
+    }
+
+    /// Gets the number of items.
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            ItemHeights::Fixed { count, .. } => *count,
+            ItemHeights::Variable { offsets } => offsets.len() - 1,
+        }
+    }
+
+    /// Whether there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the height of all items together.
+    pub fn total_height(&self) -> f64 {
+        self.offset(self.len())
+    }
+
+    /// Gets the offset of the top of an item. The offset of the item after
+    /// the last one is the total height.
+    pub fn offset(&self, index: usize) -> f64 {
+        let index = index.min(self.len());
+
+        match &self.0 {
+            ItemHeights::Fixed { height, .. } => index as f64 * height,
+            ItemHeights::Variable { offsets } => offsets[index],
+        }
+    }
+
+    /// Gets the height of an item.
+    pub fn height(&self, index: usize) -> f64 {
+        self.offset(index + 1) - self.offset(index)
+    }
+
+    /// Gets the index of the item at an offset, clamped to the first and last
+    /// items.
+    ///
+    /// ```
+    /// # use leptonic::ListLayout;
+    /// let layout = ListLayout::fixed(100, 20.0);
+    /// assert_eq!(layout.index_at(0.0), 0);
+    /// assert_eq!(layout.index_at(39.0), 1);
+    /// assert_eq!(layout.index_at(40.0), 2);
+    /// assert_eq!(layout.index_at(5000.0), 99);
+    ///
+    /// let layout = ListLayout::variable([20.0, 40.0, 30.0]);
+    /// assert_eq!(layout.index_at(59.0), 1);
+    /// assert_eq!(layout.index_at(60.0), 2);
+    /// ```
+    pub fn index_at(&self, offset: f64) -> usize {
+        let last = self.len().saturating_sub(1);
+
+        match &self.0 {
+            ItemHeights::Fixed { height, .. } if *height > 0.0 => {
+                ((offset.max(0.0) / height).floor() as usize).min(last)
+            }
+            ItemHeights::Fixed { .. } => 0,
+            ItemHeights::Variable { offsets } => offsets
+                .partition_point(|&top| top <= offset)
+                .saturating_sub(1)
+                .min(last),
+        }
+    }
+
+    /// Gets the range of items to render for a viewport scrolled to
+    /// `scroll_top`, including `overscan` extra items on either side.
+    ///
+    /// ```
+    /// # use leptonic::ListLayout;
+    /// let layout = ListLayout::fixed(10_000, 20.0);
+    /// assert_eq!(layout.visible_range(0.0, 100.0, 3), 0..9);
+    /// assert_eq!(layout.visible_range(1000.0, 100.0, 3), 47..59);
+    /// assert_eq!(layout.visible_range(199_900.0, 100.0, 3), 9992..10_000);
+    /// assert_eq!(ListLayout::fixed(0, 20.0).visible_range(0.0, 100.0, 3), 0..0);
+    /// ```
+    pub fn visible_range(
+        &self,
+        scroll_top: f64,
+        viewport_height: f64,
+        overscan: usize,
+    ) -> Range<usize> {
+        if self.is_empty() {
+            return 0..0;
+        }
+
+        let first = self.index_at(scroll_top);
+        let last = self.index_at(scroll_top + viewport_height);
+        first.saturating_sub(overscan)..(last + 1 + overscan).min(self.len())
+    }
+
+    /// Gets the scroll offset that brings an item into view, for a viewport
+    /// currently scrolled to `scroll_top`.
+    ///
+    /// ```
+    /// # use leptonic::{ListLayout, ScrollAlign};
+    /// let layout = ListLayout::fixed(100, 20.0);
+    /// assert_eq!(layout.scroll_offset(10, ScrollAlign::Start, 0.0, 100.0), 200.0);
+    /// assert_eq!(layout.scroll_offset(10, ScrollAlign::End, 0.0, 100.0), 120.0);
+    /// assert_eq!(layout.scroll_offset(10, ScrollAlign::Center, 0.0, 100.0), 160.0);
+    ///
+    /// // Items already in view are not scrolled to.
+    /// assert_eq!(layout.scroll_offset(2, ScrollAlign::Auto, 20.0, 100.0), 20.0);
+    /// assert_eq!(layout.scroll_offset(10, ScrollAlign::Auto, 20.0, 100.0), 120.0);
+    ///
+    /// // The offset stays within the scrollable area.
+    /// assert_eq!(layout.scroll_offset(99, ScrollAlign::Start, 0.0, 100.0), 1900.0);
+    /// ```
+    pub fn scroll_offset(
+        &self,
+        index: usize,
+        align: ScrollAlign,
+        scroll_top: f64,
+        viewport_height: f64,
+    ) -> f64 {
+        let index = index.min(self.len().saturating_sub(1));
+        let top = self.offset(index);
+        let bottom = top + self.height(index);
+        let offset = match align {
+            ScrollAlign::Start => top,
+            ScrollAlign::End => bottom - viewport_height,
+            ScrollAlign::Center => (top + bottom - viewport_height) / 2.0,
+            ScrollAlign::Auto if top < scroll_top => top,
+            ScrollAlign::Auto if bottom > scroll_top + viewport_height => bottom - viewport_height,
+            ScrollAlign::Auto => scroll_top,
+        };
+
+        offset.clamp(0.0, (self.total_height() - viewport_height).max(0.0))
+    }
+}